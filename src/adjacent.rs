@@ -0,0 +1,145 @@
+//! Allocation-free iteration over the neighbours of a [Point].
+use crate::{Point, Rect};
+use std::{array, iter::FusedIterator};
+
+/// An iterator over a fixed set of points, skipping any that lie outside of
+/// a rect. Created by [Point::adjacent_in_bounds] and its relatives.
+///
+/// # Examples
+///
+/// ```
+/// use point::{InBounds, Point, Rect};
+///
+/// let bounds = Rect::from_size(Point::ORIGIN, 3, 3);
+/// let corners = [Point::new(0, 0), Point::new(3, 0), Point::new(2, 2), Point::new(-1, 2)];
+///
+/// assert_eq!(InBounds::new(corners, bounds).collect::<Vec<_>>(), vec![Point::new(0, 0), Point::new(2, 2)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct InBounds<const N: usize> {
+    points: array::IntoIter<Point, N>,
+    bounds: Rect,
+}
+
+impl<const N: usize> InBounds<N> {
+    /// Returns an iterator over the points that lie within bounds, in the
+    /// order they are given.
+    pub fn new(points: [Point; N], bounds: Rect) -> Self {
+        Self {
+            points: points.into_iter(),
+            bounds,
+        }
+    }
+}
+
+impl<const N: usize> Iterator for InBounds<N> {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let bounds = self.bounds;
+        self.points.find(|p| bounds.contains(*p))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.points.len()))
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for InBounds<N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let bounds = self.bounds;
+        self.points.rfind(|p| bounds.contains(*p))
+    }
+}
+
+impl<const N: usize> FusedIterator for InBounds<N> {}
+
+impl Point {
+    /// Returns the four points orthogonally adjacent to the point, in the
+    /// order east, south, west, north.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(0, 0);
+    ///
+    /// assert_eq!(p.adjacent(), [Point::new(1, 0), Point::new(0, -1), Point::new(-1, 0), Point::new(0, 1)]);
+    /// ```
+    #[inline]
+    pub const fn adjacent(&self) -> [Self; 4] {
+        let Self { x, y } = *self;
+        [
+            Self::new(x + 1, y),
+            Self::new(x, y - 1),
+            Self::new(x - 1, y),
+            Self::new(x, y + 1),
+        ]
+    }
+
+    /// The same as adjacent, but also returns diagonally adjacent points.
+    /// The points are ordered by x co-ordinate, then by y co-ordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let neighbours = Point::new(2, 2).adjacent_diagonal();
+    ///
+    /// assert_eq!(neighbours[0], Point::new(1, 1));
+    /// assert_eq!(neighbours[7], Point::new(3, 3));
+    /// assert!(!neighbours.contains(&Point::new(2, 2)));
+    /// ```
+    #[inline]
+    pub const fn adjacent_diagonal(&self) -> [Self; 8] {
+        let Self { x, y } = *self;
+        [
+            Self::new(x - 1, y - 1),
+            Self::new(x - 1, y),
+            Self::new(x - 1, y + 1),
+            Self::new(x, y - 1),
+            Self::new(x, y + 1),
+            Self::new(x + 1, y - 1),
+            Self::new(x + 1, y),
+            Self::new(x + 1, y + 1),
+        ]
+    }
+
+    /// Returns an iterator over the orthogonally adjacent points that pass
+    /// [Point::bounds_check] with the given maximum x and y co-ordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(0, 3);
+    ///
+    /// assert_eq!(p.adjacent_in_bounds(5, 4).collect::<Vec<_>>(), vec![Point::new(1, 3), Point::new(0, 2)]);
+    /// ```
+    pub fn adjacent_in_bounds(&self, max_x: i32, max_y: i32) -> InBounds<4> {
+        self.adjacent_in_rect(&Rect::from_size(Point::ORIGIN, max_x, max_y))
+    }
+
+    /// Returns an iterator over the orthogonally adjacent points that lie
+    /// within the given rect.
+    pub fn adjacent_in_rect(&self, bounds: &Rect) -> InBounds<4> {
+        InBounds::new(self.adjacent(), *bounds)
+    }
+
+    /// The same as adjacent_in_bounds, but also includes diagonally adjacent
+    /// points.
+    pub fn adjacent_diagonal_in_bounds(&self, max_x: i32, max_y: i32) -> InBounds<8> {
+        self.adjacent_diagonal_in_rect(&Rect::from_size(Point::ORIGIN, max_x, max_y))
+    }
+
+    /// The same as adjacent_in_rect, but also includes diagonally adjacent
+    /// points.
+    pub fn adjacent_diagonal_in_rect(&self, bounds: &Rect) -> InBounds<8> {
+        InBounds::new(self.adjacent_diagonal(), *bounds)
+    }
+}
@@ -0,0 +1,378 @@
+//! Cellular automata over [Grid]s.
+//!
+//! An [Automaton] repeatedly replaces every cell of a grid with the result of
+//! a [Rule] applied to the cell and its eight neighbours, as given by
+//! [Point::adjacent_diagonal](crate::Point::adjacent_diagonal). The edges of the grid are handled by a
+//! [Wrap]; clipped edges are treated as if surrounded by a fixed boundary
+//! value.
+use crate::{
+    Grid,
+    topology::{Topology, Wrap},
+};
+use std::{error, fmt, str::FromStr};
+
+/// A rule giving the next state of a cell from its current state and those of
+/// its eight neighbours, in the same order as [Point::adjacent_diagonal](crate::Point::adjacent_diagonal).
+///
+/// Rules are implemented for closures, so any function of the neighbourhood
+/// can be used with an [Automaton].
+pub trait Rule<T> {
+    /// Returns the next state of a cell.
+    fn apply(&self, cell: &T, neighbours: [&T; 8]) -> T;
+}
+
+impl<T, F> Rule<T> for F
+where
+    F: Fn(&T, [&T; 8]) -> T,
+{
+    #[inline]
+    fn apply(&self, cell: &T, neighbours: [&T; 8]) -> T {
+        self(cell, neighbours)
+    }
+}
+
+/// A rule for automata whose cells are either alive or dead, like Conway's
+/// Game of Life, in which the next state only depends on the number of live
+/// neighbours.
+///
+/// Rules are written in birth/survival notation: `B3/S23` means a dead cell
+/// comes alive with exactly 3 live neighbours, and a live cell survives with
+/// 2 or 3 live neighbours.
+///
+/// # Examples
+///
+/// ```
+/// use point::automaton::LifeRule;
+///
+/// let rule: LifeRule = "B36/S23".parse().unwrap();
+///
+/// assert!(rule.is_born(6));
+/// assert!(!rule.survives(6));
+/// assert_eq!(rule.to_string(), "B36/S23");
+/// assert_eq!("b3/s23".parse(), Ok(LifeRule::LIFE));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct LifeRule {
+    birth: u16,
+    survival: u16,
+}
+
+impl LifeRule {
+    /// Conway's Game of Life, `B3/S23`.
+    pub const LIFE: Self = Self::new(&[3], &[2, 3]);
+    /// A rule for smoothing randomly filled cells into caves, `B5678/S45678`,
+    /// where live cells are walls.
+    pub const CAVE: Self = Self::new(&[5, 6, 7, 8], &[4, 5, 6, 7, 8]);
+
+    /// Returns the rule where dead cells come alive with any of the given
+    /// numbers of live neighbours, and live cells survive with any of the
+    /// given numbers of live neighbours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the numbers is greater than 8.
+    pub const fn new(birth: &[u8], survival: &[u8]) -> Self {
+        Self {
+            birth: mask(birth),
+            survival: mask(survival),
+        }
+    }
+
+    /// Returns true if a dead cell with the given number of live neighbours
+    /// comes alive.
+    #[inline]
+    pub const fn is_born(&self, live_neighbours: u32) -> bool {
+        live_neighbours < 9 && self.birth & (1 << live_neighbours) != 0
+    }
+
+    /// Returns true if a live cell with the given number of live neighbours
+    /// stays alive.
+    #[inline]
+    pub const fn survives(&self, live_neighbours: u32) -> bool {
+        live_neighbours < 9 && self.survival & (1 << live_neighbours) != 0
+    }
+}
+
+/// Returns a bit mask with the bit for each count set.
+const fn mask(counts: &[u8]) -> u16 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < counts.len() {
+        assert!(counts[i] <= 8, "neighbour counts must be at most 8");
+        mask |= 1 << counts[i];
+        i += 1;
+    }
+    mask
+}
+
+impl Rule<bool> for LifeRule {
+    #[inline]
+    fn apply(&self, cell: &bool, neighbours: [&bool; 8]) -> bool {
+        let live = neighbours.iter().filter(|&&&n| n).count() as u32;
+        if *cell {
+            self.survives(live)
+        } else {
+            self.is_born(live)
+        }
+    }
+}
+
+impl fmt::Display for LifeRule {
+    /// Formats the rule in birth/survival notation, such as `B3/S23`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("B")?;
+        for n in (0..9).filter(|&n| self.is_born(n)) {
+            write!(f, "{n}")?;
+        }
+        f.write_str("/S")?;
+        for n in (0..9).filter(|&n| self.survives(n)) {
+            write!(f, "{n}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when parsing a [LifeRule] from a string fails.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum ParseRuleError {
+    /// The string was not of the form `Bxx/Sxx`.
+    InvalidFormat,
+    /// A neighbour count was not a digit from 0 to 8.
+    InvalidCount,
+}
+
+impl fmt::Display for ParseRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidFormat => "invalid rule syntax",
+            Self::InvalidCount => "invalid neighbour count in rule",
+        })
+    }
+}
+
+impl error::Error for ParseRuleError {}
+
+impl FromStr for LifeRule {
+    type Err = ParseRuleError;
+
+    /// Parses a rule in birth/survival notation, such as `B3/S23`. The two
+    /// halves may be in either order, and the letters may be lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::automaton::{LifeRule, ParseRuleError};
+    ///
+    /// assert_eq!("S23/B3".parse(), Ok(LifeRule::LIFE));
+    /// assert_eq!("B/S".parse(), Ok(LifeRule::new(&[], &[])));
+    /// assert_eq!("B3".parse::<LifeRule>(), Err(ParseRuleError::InvalidFormat));
+    /// assert_eq!("B39/S23".parse::<LifeRule>(), Err(ParseRuleError::InvalidCount));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s
+            .trim()
+            .split_once('/')
+            .ok_or(ParseRuleError::InvalidFormat)?;
+        let (mut birth, mut survival) = (None, None);
+
+        for part in [first, second] {
+            let mut chars = part.chars();
+            let slot = match chars.next().map(|c| c.to_ascii_uppercase()) {
+                Some('B') if birth.is_none() => &mut birth,
+                Some('S') if survival.is_none() => &mut survival,
+                _ => return Err(ParseRuleError::InvalidFormat),
+            };
+            let mut mask = 0u16;
+            for c in chars {
+                match c.to_digit(10) {
+                    Some(n) if n <= 8 => mask |= 1 << n,
+                    _ => return Err(ParseRuleError::InvalidCount),
+                }
+            }
+            *slot = Some(mask);
+        }
+
+        match (birth, survival) {
+            (Some(birth), Some(survival)) => Ok(Self { birth, survival }),
+            _ => Err(ParseRuleError::InvalidFormat),
+        }
+    }
+}
+
+/// A double-buffered cellular automaton.
+///
+/// # Examples
+///
+/// ```
+/// use point::automaton::{Automaton, LifeRule};
+/// use point::topology::Wrap;
+/// use point::{Grid, Point};
+///
+/// // A glider on a torus returns to where it started after 4 generations
+/// // for every cell it moves diagonally.
+/// let mut cells = Grid::new(8, 8, false);
+/// for p in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+///     cells[Point::from(p)] = true;
+/// }
+/// let mut life = Automaton::new(cells.clone(), Wrap::Torus, false);
+///
+/// life.run(32, LifeRule::LIFE);
+/// assert_eq!(life.generation(), 32);
+/// assert_eq!(life.cells(), &cells);
+/// ```
+///
+/// Closures can be used as rules, and runs can stop once the cells stop
+/// changing:
+///
+/// ```
+/// use point::automaton::Automaton;
+/// use point::topology::Wrap;
+/// use point::{Grid, Point};
+///
+/// // Each cell takes the largest value in its neighbourhood.
+/// let mut cells = Grid::new(5, 5, 0);
+/// cells[Point::new(0, 0)] = 9;
+/// let mut spread = Automaton::new(cells, Wrap::Clipped, 0);
+///
+/// let generations = spread.run_until_stable(100, |&c: &i32, n: [&i32; 8]| n.into_iter().fold(c, |a, &b| a.max(b)));
+///
+/// // Four generations to fill the grid, and one more to see it is stable.
+/// assert_eq!(generations, 5);
+/// assert!(spread.cells().iter().all(|&c| c == 9));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Automaton<T> {
+    cells: Grid<T>,
+    buffer: Grid<T>,
+    wrap: Wrap,
+    boundary: T,
+    generation: u64,
+}
+
+impl<T: Clone> Automaton<T> {
+    /// Returns a new automaton starting from the given cells, with the given
+    /// edges wrapping around. Neighbours beyond clipped edges take the value
+    /// of boundary.
+    pub fn new(cells: Grid<T>, wrap: Wrap, boundary: T) -> Self {
+        Self {
+            buffer: cells.clone(),
+            cells,
+            wrap,
+            boundary,
+            generation: 0,
+        }
+    }
+
+    /// Returns the current cells.
+    #[inline]
+    pub fn cells(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    /// Returns the current cells mutably, so that they can be edited between
+    /// generations. The grid may even be replaced with one of a different
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::automaton::{Automaton, LifeRule};
+    /// use point::topology::Wrap;
+    /// use point::{Grid, Point};
+    ///
+    /// let mut life = Automaton::new(Grid::new(3, 3, false), Wrap::Clipped, false);
+    ///
+    /// // A blinker, which needs more room than the original grid.
+    /// *life.cells_mut() = Grid::from_fn(5, 5, |p| p.y == 2 && (1..4).contains(&p.x));
+    /// life.step(LifeRule::LIFE);
+    ///
+    /// assert_eq!((life.cells().width(), life.cells().height()), (5, 5));
+    /// assert!(life.cells()[Point::new(2, 1)] && life.cells()[Point::new(2, 3)]);
+    /// ```
+    #[inline]
+    pub fn cells_mut(&mut self) -> &mut Grid<T> {
+        &mut self.cells
+    }
+
+    /// Consumes the automaton, returning the current cells.
+    pub fn into_grid(self) -> Grid<T> {
+        self.cells
+    }
+
+    /// Returns the number of generations that have been run.
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns which edges of the grid wrap around.
+    #[inline]
+    pub const fn wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    /// Runs a single generation.
+    pub fn step<R: Rule<T>>(&mut self, rule: R) {
+        self.advance(&rule);
+    }
+
+    /// Runs the given number of generations.
+    pub fn run<R: Rule<T>>(&mut self, generations: usize, rule: R) {
+        for _ in 0..generations {
+            self.advance(&rule);
+        }
+    }
+
+    /// Runs a single generation, returning true if any cell changed.
+    pub fn step_changed<R: Rule<T>>(&mut self, rule: R) -> bool
+    where
+        T: PartialEq,
+    {
+        self.advance(&rule);
+        self.cells != self.buffer
+    }
+
+    /// Runs generations until one leaves every cell unchanged, or until
+    /// max_generations have been run. Returns the number of generations run,
+    /// including the final unchanged one.
+    pub fn run_until_stable<R: Rule<T>>(&mut self, max_generations: usize, rule: R) -> usize
+    where
+        T: PartialEq,
+    {
+        for i in 0..max_generations {
+            self.advance(&rule);
+            if self.cells == self.buffer {
+                return i + 1;
+            }
+        }
+        max_generations
+    }
+
+    /// Writes the next generation into the buffer, then swaps it with the
+    /// cells, leaving the previous generation in the buffer.
+    fn advance<R: Rule<T>>(&mut self, rule: &R) {
+        self.generation += 1;
+        // The cells may have been replaced through cells_mut.
+        let size = (self.cells.width(), self.cells.height());
+        if (self.buffer.width(), self.buffer.height()) != size {
+            self.buffer = self.cells.clone();
+        }
+        if self.cells.is_empty() {
+            return;
+        }
+        let topology = Topology::new(
+            self.cells.width() as i32,
+            self.cells.height() as i32,
+            self.wrap,
+        );
+        let (cells, boundary) = (&self.cells, &self.boundary);
+
+        for (pos, next) in self.buffer.enumerate_mut() {
+            let neighbours = pos
+                .adjacent_diagonal()
+                .map(|p| topology.normalize(p).map_or(boundary, |p| &cells[p]));
+            *next = rule.apply(&cells[pos], neighbours);
+        }
+        std::mem::swap(&mut self.cells, &mut self.buffer);
+    }
+}
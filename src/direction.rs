@@ -0,0 +1,309 @@
+//! Compass directions between adjacent [Point]s.
+//!
+//! Directions treat the y axis as pointing north, matching [Point::dir], so
+//! rotating clockwise agrees with [Point::rotate_90_cw].
+use crate::Point;
+use std::{error, fmt, ops};
+
+/// One of the four orthogonal directions.
+///
+/// The discriminant of each direction is the same as the result of
+/// [Point::dir] on the corresponding unit point, so a direction can be used to
+/// index a collection previously indexed by dir.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Direction, Point};
+///
+/// let mut costs = [0; 4];
+/// costs[Direction::North] = 3;
+///
+/// assert_eq!(costs[Point::new(0, 1).dir()], 3);
+/// assert_eq!(Direction::try_from(Point::new(0, 1)), Ok(Direction::North));
+/// assert!(Direction::try_from(Point::new(1, 1)).is_err());
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Towards negative y.
+    South = 0,
+    /// Towards negative x.
+    West = 1,
+    /// Towards positive y.
+    North = 2,
+    /// Towards positive x.
+    East = 3,
+}
+
+impl Direction {
+    /// Every direction, in order of their discriminants.
+    pub const ALL: [Self; 4] = [Self::South, Self::West, Self::North, Self::East];
+
+    /// Returns the unit point pointing in this direction.
+    #[inline]
+    pub const fn to_point(self) -> Point {
+        match self {
+            Self::South => Point::new(0, -1),
+            Self::West => Point::new(-1, 0),
+            Self::North => Point::new(0, 1),
+            Self::East => Point::new(1, 0),
+        }
+    }
+
+    /// Returns the direction pointing the other way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Direction;
+    ///
+    /// assert_eq!(Direction::North.opposite(), Direction::South);
+    /// assert_eq!(Direction::West.opposite(), Direction::East);
+    /// ```
+    #[inline]
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Returns the direction rotated by 90 degrees clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Direction;
+    ///
+    /// assert_eq!(Direction::North.rotate_cw(), Direction::East);
+    /// assert_eq!(Direction::North.rotate_cw().to_point(), Direction::North.to_point().rotate_90_cw());
+    /// ```
+    #[inline]
+    pub const fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Returns the direction rotated by 90 degrees anti-clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Direction;
+    ///
+    /// assert_eq!(Direction::North.rotate_acw(), Direction::West);
+    /// ```
+    #[inline]
+    pub const fn rotate_acw(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions.
+///
+/// Directions are numbered clockwise starting from south, so each
+/// [Direction] has a discriminant half that of its counterpart.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Direction8, Point};
+///
+/// let p = Point::new(3, 3);
+/// let neighbours: Vec<Point> = Direction8::ALL.iter().map(|d| p + d.to_point()).collect();
+///
+/// assert_eq!(neighbours.len(), 8);
+/// assert_eq!(Direction8::try_from(Point::new(-1, 1)), Ok(Direction8::NorthWest));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction8 {
+    /// Towards negative y.
+    South = 0,
+    /// Towards negative x and negative y.
+    SouthWest = 1,
+    /// Towards negative x.
+    West = 2,
+    /// Towards negative x and positive y.
+    NorthWest = 3,
+    /// Towards positive y.
+    North = 4,
+    /// Towards positive x and positive y.
+    NorthEast = 5,
+    /// Towards positive x.
+    East = 6,
+    /// Towards positive x and negative y.
+    SouthEast = 7,
+}
+
+impl Direction8 {
+    /// Every direction, in order of their discriminants.
+    pub const ALL: [Self; 8] = [
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+    ];
+
+    /// Returns the point one step away from the origin in this direction.
+    #[inline]
+    pub const fn to_point(self) -> Point {
+        match self {
+            Self::South => Point::new(0, -1),
+            Self::SouthWest => Point::new(-1, -1),
+            Self::West => Point::new(-1, 0),
+            Self::NorthWest => Point::new(-1, 1),
+            Self::North => Point::new(0, 1),
+            Self::NorthEast => Point::new(1, 1),
+            Self::East => Point::new(1, 0),
+            Self::SouthEast => Point::new(1, -1),
+        }
+    }
+
+    /// Returns true if the direction is one of the four diagonals.
+    #[inline]
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Returns the direction pointing the other way.
+    #[inline]
+    pub const fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Returns the direction rotated by 45 degrees clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Direction8;
+    ///
+    /// assert_eq!(Direction8::North.rotate_cw(), Direction8::NorthEast);
+    /// assert_eq!(Direction8::SouthEast.rotate_cw(), Direction8::South);
+    /// ```
+    #[inline]
+    pub const fn rotate_cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Returns the direction rotated by 45 degrees anti-clockwise.
+    #[inline]
+    pub const fn rotate_acw(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    /// Returns the orthogonal direction, or the given direction as the error
+    /// if it is diagonal.
+    fn try_from(dir: Direction8) -> Result<Self, Self::Error> {
+        if dir.is_diagonal() {
+            Err(dir)
+        } else {
+            Ok(Self::ALL[dir as usize / 2])
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(dir: Direction) -> Self {
+        dir.to_point()
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(dir: Direction8) -> Self {
+        dir.to_point()
+    }
+}
+
+/// The error returned when converting a point that is not one step away from
+/// the origin into a direction.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct TryFromPointError(pub Point);
+
+impl fmt::Display for TryFromPointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a unit direction", self.0)
+    }
+}
+
+impl error::Error for TryFromPointError {}
+
+impl TryFrom<Point> for Direction {
+    type Error = TryFromPointError;
+
+    fn try_from(p: Point) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|d| d.to_point() == p)
+            .ok_or(TryFromPointError(p))
+    }
+}
+
+impl TryFrom<Point> for Direction8 {
+    type Error = TryFromPointError;
+
+    fn try_from(p: Point) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|d| d.to_point() == p)
+            .ok_or(TryFromPointError(p))
+    }
+}
+
+impl<T> ops::Index<Direction> for [T; 4] {
+    type Output = T;
+
+    fn index(&self, dir: Direction) -> &Self::Output {
+        &self[dir as usize]
+    }
+}
+
+impl<T> ops::IndexMut<Direction> for [T; 4] {
+    fn index_mut(&mut self, dir: Direction) -> &mut Self::Output {
+        &mut self[dir as usize]
+    }
+}
+
+impl<T> ops::Index<Direction8> for [T; 8] {
+    type Output = T;
+
+    fn index(&self, dir: Direction8) -> &Self::Output {
+        &self[dir as usize]
+    }
+}
+
+impl<T> ops::IndexMut<Direction8> for [T; 8] {
+    fn index_mut(&mut self, dir: Direction8) -> &mut Self::Output {
+        &mut self[dir as usize]
+    }
+}
+
+impl ops::Add<Direction> for Point {
+    type Output = Self;
+
+    /// Returns the point one step away in the given direction.
+    fn add(self, dir: Direction) -> Self::Output {
+        self + dir.to_point()
+    }
+}
+
+impl ops::Add<Direction8> for Point {
+    type Output = Self;
+
+    /// Returns the point one step away in the given direction.
+    fn add(self, dir: Direction8) -> Self::Output {
+        self + dir.to_point()
+    }
+}
@@ -0,0 +1,216 @@
+//! Flood fills and connected component labelling.
+//!
+//! Like the searches in [pathfinding](crate::pathfinding), the flood fills
+//! work over the unbounded plane, so the closure deciding which points are
+//! inside the filled region is also responsible for limiting it, typically by
+//! returning false for any point outside of the map.
+use crate::{Grid, Point, Rect, pathfinding::Neighbourhood};
+use std::collections::{HashSet, VecDeque};
+
+/// Returns every point connected to start through points for which is_inside
+/// returns true, including start itself. Returns an empty set if start is not
+/// inside.
+///
+/// is_inside is called at most once for each point.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::flood::flood_fill;
+/// use point::pathfinding::Neighbourhood;
+///
+/// // Two squares touching at a corner.
+/// let is_inside = |p: Point| (p.bounds_check(2, 2) || (p - Point::new(2, 2)).bounds_check(2, 2));
+///
+/// assert_eq!(flood_fill(Point::ORIGIN, Neighbourhood::Four, is_inside).len(), 4);
+/// assert_eq!(flood_fill(Point::ORIGIN, Neighbourhood::Eight, is_inside).len(), 8);
+/// ```
+pub fn flood_fill<P>(start: Point, neighbourhood: Neighbourhood, mut is_inside: P) -> HashSet<Point>
+where
+    P: FnMut(Point) -> bool,
+{
+    let mut filled = HashSet::new();
+    if !is_inside(start) {
+        return filled;
+    }
+    let mut queue = VecDeque::from([start]);
+    let mut seen = HashSet::from([start]);
+
+    while let Some(pos) = queue.pop_front() {
+        filled.insert(pos);
+        for next in neighbourhood.neighbours(pos) {
+            if seen.insert(next) && is_inside(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    filled
+}
+
+/// Returns the same points as [flood_fill], but fills whole runs of points
+/// along each row at once.
+///
+/// This is usually much faster than [flood_fill] for large, open regions, as
+/// far fewer points have to be queued. Unlike [flood_fill], is_inside may be
+/// called more than once for points outside the region.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::flood::{flood_fill, scanline_fill};
+/// use point::pathfinding::Neighbourhood;
+///
+/// // A ring, with a gap in its wall.
+/// let is_inside = |p: Point| {
+///     let d = p.dist_squared(Point::new(10, 10));
+///     (25..=64).contains(&d) && p != Point::new(10, 3)
+/// };
+/// let start = Point::new(10, 17);
+///
+/// let fast = scanline_fill(start, Neighbourhood::Four, is_inside);
+///
+/// assert_eq!(fast, flood_fill(start, Neighbourhood::Four, is_inside));
+/// assert!(!fast.contains(&Point::new(10, 10)));
+/// ```
+pub fn scanline_fill<P>(
+    start: Point,
+    neighbourhood: Neighbourhood,
+    mut is_inside: P,
+) -> HashSet<Point>
+where
+    P: FnMut(Point) -> bool,
+{
+    let mut filled = HashSet::new();
+    // Diagonal connections reach one point further along the adjacent rows.
+    let reach = match neighbourhood {
+        Neighbourhood::Four => 0,
+        Neighbourhood::Eight => 1,
+    };
+    let mut seeds = vec![start];
+
+    while let Some(seed) = seeds.pop() {
+        if filled.contains(&seed) || !is_inside(seed) {
+            continue;
+        }
+
+        // Extend the run containing the seed as far as possible each way.
+        let mut left = seed.x;
+        while !filled.contains(&Point::new(left - 1, seed.y))
+            && is_inside(Point::new(left - 1, seed.y))
+        {
+            left -= 1;
+        }
+        let mut right = seed.x;
+        while !filled.contains(&Point::new(right + 1, seed.y))
+            && is_inside(Point::new(right + 1, seed.y))
+        {
+            right += 1;
+        }
+        filled.extend((left..=right).map(|x| Point::new(x, seed.y)));
+
+        // Seed the start of each run of unfilled points in the adjacent rows.
+        for y in [seed.y - 1, seed.y + 1] {
+            let mut in_run = false;
+            for x in left - reach..=right + reach {
+                let p = Point::new(x, y);
+                let inside = !filled.contains(&p) && is_inside(p);
+                if inside && !in_run {
+                    seeds.push(p);
+                }
+                in_run = inside;
+            }
+        }
+    }
+
+    filled
+}
+
+/// A set of connected points found by [label_components].
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Component {
+    /// The points in the component, in row-major order.
+    pub points: Vec<Point>,
+    /// The smallest rect containing every point in the component.
+    pub bounds: Rect,
+}
+
+/// The connected components of a [Grid], as returned by [label_components].
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Components {
+    /// The index into components of the component containing each cell, or
+    /// None for cells that are not members of any component.
+    pub labels: Grid<Option<usize>>,
+    /// Every component, ordered by the position of their first point in
+    /// row-major order.
+    pub components: Vec<Component>,
+}
+
+/// Splits the cells of the grid for which is_member returns true into
+/// connected components.
+///
+/// is_member is called exactly once for each cell.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Grid, Point, Rect};
+/// use point::flood::label_components;
+/// use point::pathfinding::Neighbourhood;
+///
+/// let rows = ["##..#", "....#", "##.##"];
+/// let map = Grid::from_vec(5, 3, rows.concat().chars().collect()).unwrap();
+/// let walls = label_components(&map, Neighbourhood::Four, |&c| c == '#');
+///
+/// assert_eq!(walls.components.len(), 3);
+/// assert_eq!(walls.labels[Point::new(4, 0)], Some(1));
+/// assert_eq!(walls.labels[Point::new(3, 2)], Some(1));
+/// assert_eq!(walls.labels[Point::new(2, 0)], None);
+/// assert_eq!(walls.components[1].bounds, Rect::new(Point::new(3, 0), Point::new(5, 3)));
+/// ```
+pub fn label_components<T, P>(
+    grid: &Grid<T>,
+    neighbourhood: Neighbourhood,
+    is_member: P,
+) -> Components
+where
+    P: FnMut(&T) -> bool,
+{
+    let members = grid.map(is_member);
+    let mut labels = Grid::new(grid.width(), grid.height(), None);
+    let mut components = Vec::new();
+
+    for start in grid.points() {
+        if !members[start] || labels[start].is_some() {
+            continue;
+        }
+        let label = components.len();
+        labels[start] = Some(label);
+        let mut queue = VecDeque::from([start]);
+        let mut points = Vec::new();
+        let (mut min, mut max) = (start, start);
+
+        while let Some(pos) = queue.pop_front() {
+            points.push(pos);
+            min = Point::new(min.x.min(pos.x), min.y.min(pos.y));
+            max = Point::new(max.x.max(pos.x), max.y.max(pos.y));
+
+            for next in neighbourhood.neighbours(pos) {
+                if members.get(next) == Some(&true) && labels[next].is_none() {
+                    labels[next] = Some(label);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        points.sort_unstable_by_key(|p| (p.y, p.x));
+        components.push(Component {
+            points,
+            bounds: Rect::from_corners(min, max),
+        });
+    }
+
+    Components { labels, components }
+}
@@ -0,0 +1,177 @@
+//! Field of view calculations.
+//!
+//! Uses [symmetric shadowcasting](https://www.albertford.com/shadowcasting/),
+//! which scans outwards from the origin one row at a time in each of the four
+//! quadrants, tracking the slopes of the shadows cast by opaque points. Unlike
+//! casting a ray to every point with [Point::plot_line], the result is
+//! symmetric: if a can see b, then b can see a.
+use crate::Point;
+use std::collections::HashSet;
+
+/// An exact rational slope, col / depth, with a positive denominator.
+#[derive(Clone, Copy)]
+struct Slope {
+    num: i64,
+    den: i64,
+}
+
+impl Slope {
+    /// Returns the slope of the edge of the tile at the given depth and
+    /// column closest to the start of the row.
+    fn of_tile(depth: i64, col: i64) -> Self {
+        Self {
+            num: 2 * col - 1,
+            den: 2 * depth,
+        }
+    }
+
+    /// Returns depth * self rounded to the nearest integer, rounding ties up.
+    fn round_ties_up(self, depth: i64) -> i64 {
+        (2 * depth * self.num + self.den).div_euclid(2 * self.den)
+    }
+
+    /// Returns depth * self rounded to the nearest integer, rounding ties down.
+    fn round_ties_down(self, depth: i64) -> i64 {
+        -(-2 * depth * self.num + self.den).div_euclid(2 * self.den)
+    }
+}
+
+/// A row of tiles within a quadrant, bounded by a start and end slope.
+#[derive(Clone, Copy)]
+struct Row {
+    depth: i64,
+    start: Slope,
+    end: Slope,
+}
+
+impl Row {
+    /// Returns true if the column lies within the row's slopes, meaning it is
+    /// visible from the origin and the origin is visible from it.
+    fn is_symmetric(&self, col: i64) -> bool {
+        col * self.start.den >= self.depth * self.start.num
+            && col * self.end.den <= self.depth * self.end.num
+    }
+
+    fn next(&self) -> Self {
+        Self {
+            depth: self.depth + 1,
+            ..*self
+        }
+    }
+}
+
+/// Returns every point visible from origin within radius, using symmetric
+/// shadowcasting.
+///
+/// is_opaque is called with each point the scan reaches, and should return true
+/// if the point blocks vision. Opaque points are themselves visible if light
+/// reaches them. A point is only visible if its Euclidean distance from origin
+/// is at most radius. The origin is always visible.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::fov::shadowcast;
+///
+/// // A single pillar at (2, 0) casts a shadow along the positive x axis.
+/// let pillar = Point::new(2, 0);
+/// let visible = shadowcast(Point::ORIGIN, 5, |p| p == pillar);
+///
+/// assert!(visible.contains(&Point::ORIGIN));
+/// assert!(visible.contains(&pillar));
+/// assert!(visible.contains(&Point::new(-5, 0)));
+/// assert!(!visible.contains(&Point::new(4, 0)));
+/// assert!(!visible.contains(&Point::new(4, 4)));
+/// ```
+///
+/// The radius may be as large as u32::MAX, leaving only the walls of a room to
+/// limit what can be seen:
+///
+/// ```
+/// use point::Point;
+/// use point::fov::shadowcast;
+///
+/// let visible = shadowcast(Point::new(2, 2), u32::MAX, |p| !p.bounds_check(5, 5));
+///
+/// assert_eq!(visible.len(), 7 * 7);
+/// ```
+///
+/// Visibility is symmetric between any two non-opaque points:
+///
+/// ```
+/// use point::Point;
+/// use point::fov::shadowcast;
+///
+/// let walls = [(3, 1), (1, 3), (4, 4), (2, 5), (5, 2), (0, 4)].map(Point::from);
+/// let is_opaque = |p: Point| !p.bounds_check(7, 7) || walls.contains(&p);
+///
+/// for a in (0..49).map(|i| Point::convert_up(i, 7)).filter(|&p| !is_opaque(p)) {
+///     let from_a = shadowcast(a, 10, is_opaque);
+///     for b in (0..49).map(|i| Point::convert_up(i, 7)).filter(|&p| !is_opaque(p)) {
+///         let from_b = shadowcast(b, 10, is_opaque);
+///         assert_eq!(from_a.contains(&b), from_b.contains(&a));
+///     }
+/// }
+/// ```
+pub fn shadowcast<F>(origin: Point, radius: u32, mut is_opaque: F) -> HashSet<Point>
+where
+    F: FnMut(Point) -> bool,
+{
+    let mut visible = HashSet::from([origin]);
+    let radius_squared = (radius as u128).pow(2);
+    let in_range = |p: Point| p.dist_squared_wide(origin) <= radius_squared;
+    let radius = radius as i64;
+
+    // Each quadrant is scanned in local co-ordinates, where a row at depth d
+    // lies d steps from the origin along the quadrant's axis. Rotating the
+    // offset of a local tile maps it onto the corresponding quadrant.
+    let mut axis_offset = Point::new(0, 1);
+    for _ in 0..4 {
+        let (axis, side) = (axis_offset, axis_offset.rotate_90_cw());
+        let to_point = |depth: i64, col: i64| origin + axis * depth as i32 + side * col as i32;
+
+        let mut rows = vec![Row {
+            depth: 1,
+            start: Slope { num: -1, den: 1 },
+            end: Slope { num: 1, den: 1 },
+        }];
+
+        while let Some(mut row) = rows.pop() {
+            if row.depth > radius {
+                continue;
+            }
+
+            let min_col = row.start.round_ties_up(row.depth);
+            let max_col = row.end.round_ties_down(row.depth);
+            let mut prev_opaque = None;
+
+            for col in min_col..=max_col {
+                let pos = to_point(row.depth, col);
+                let opaque = is_opaque(pos);
+
+                if (opaque || row.is_symmetric(col)) && in_range(pos) {
+                    visible.insert(pos);
+                }
+                match (prev_opaque, opaque) {
+                    (Some(true), false) => row.start = Slope::of_tile(row.depth, col),
+                    (Some(false), true) => {
+                        let mut next = row.next();
+                        next.end = Slope::of_tile(row.depth, col);
+                        rows.push(next);
+                    }
+                    _ => (),
+                }
+                prev_opaque = Some(opaque);
+            }
+
+            if prev_opaque == Some(false) {
+                rows.push(row.next());
+            }
+        }
+
+        axis_offset.rotate_90_cw_ip();
+    }
+
+    visible
+}
@@ -0,0 +1,450 @@
+//! Seeded procedural generation of dungeon maps.
+//!
+//! Every generator takes a seed, and produces the same [Dungeon] for the same
+//! seed and parameters on every platform. The outermost ring of tiles is
+//! always left as [Tile::Wall], so generated maps are closed.
+use crate::{
+    Grid, Point, Rect, Rng,
+    automaton::{Automaton, LifeRule},
+    flood::label_components,
+    pathfinding::Neighbourhood,
+    topology::Wrap,
+};
+
+/// A single tile of a generated map.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tile {
+    /// Solid rock.
+    #[default]
+    Wall,
+    /// Open floor.
+    Floor,
+    /// A doorway where a corridor enters a room. Doors are open, like floor.
+    Door,
+}
+
+impl Tile {
+    /// Returns true for tiles that can be walked through.
+    #[inline]
+    pub const fn is_passable(self) -> bool {
+        !matches!(self, Self::Wall)
+    }
+}
+
+/// A generated map.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Dungeon {
+    /// The tiles of the map.
+    pub tiles: Grid<Tile>,
+    /// The floor area of every room, in the order they were created. Empty
+    /// for generators without rooms.
+    pub rooms: Vec<Rect>,
+    /// The position of every door, in row-major order.
+    pub doors: Vec<Point>,
+}
+
+impl Dungeon {
+    /// Returns a map of the given size made entirely of wall.
+    fn solid(width: i32, height: i32) -> Self {
+        Self {
+            tiles: Grid::new(width.max(0) as usize, height.max(0) as usize, Tile::Wall),
+            rooms: Vec::new(),
+            doors: Vec::new(),
+        }
+    }
+
+    /// Returns the rect within the outer ring of wall.
+    fn interior(&self) -> Rect {
+        self.tiles.bounds().inflate(-1)
+    }
+
+    /// Sets every tile in rect to floor.
+    fn carve_rect(&mut self, rect: &Rect) {
+        for p in rect.points() {
+            self.tiles.set(p, Tile::Floor);
+        }
+    }
+
+    /// Carves a corridor between a and b.
+    fn carve_corridor(&mut self, a: Point, b: Point, corridor: Corridor, rng: &mut Rng) {
+        match corridor {
+            Corridor::LShaped => {
+                let corner = if rng.chance(0.5) {
+                    Point::new(b.x, a.y)
+                } else {
+                    Point::new(a.x, b.y)
+                };
+                for p in Point::plot_line_inclusive(a, corner).chain(Point::plot_line(corner, b)) {
+                    self.tiles.set(p, Tile::Floor);
+                }
+                self.tiles.set(b, Tile::Floor);
+            }
+            Corridor::Line => {
+                let mut prev = a;
+                for p in Point::plot_line_inclusive(a, b) {
+                    // Filling in the corner of each diagonal step keeps the
+                    // corridor passable without moving diagonally.
+                    self.tiles.set(Point::new(p.x, prev.y), Tile::Floor);
+                    self.tiles.set(p, Tile::Floor);
+                    prev = p;
+                }
+            }
+        }
+    }
+
+    /// Turns each floor tile where a corridor meets the edge of a room into a
+    /// door. A tile is a door if it lies just outside a room, is not inside any
+    /// room, and is walled in on both sides along the room's edge.
+    fn place_doors(&mut self) {
+        let wall = |tiles: &Grid<Tile>, p: Point| tiles.get(p).is_none_or(|&t| t == Tile::Wall);
+        let mut doors = Vec::new();
+
+        for room in &self.rooms {
+            for p in room.inflate(1).perimeter() {
+                if self.tiles.get(p) != Some(&Tile::Floor)
+                    || self.rooms.iter().any(|r| r.contains(p))
+                {
+                    continue;
+                }
+                let along_x = p.y < room.min.y || p.y >= room.max.y;
+                let along_y = p.x < room.min.x || p.x >= room.max.x;
+                // Corners of the ring only touch the room diagonally.
+                if along_x == along_y {
+                    continue;
+                }
+                let sides = if along_x {
+                    [Point::new(p.x - 1, p.y), Point::new(p.x + 1, p.y)]
+                } else {
+                    [Point::new(p.x, p.y - 1), Point::new(p.x, p.y + 1)]
+                };
+                if sides.iter().all(|&s| wall(&self.tiles, s)) {
+                    doors.push(p);
+                }
+            }
+        }
+
+        doors.sort_unstable_by_key(|p| (p.y, p.x));
+        doors.dedup();
+        for &door in &doors {
+            self.tiles[door] = Tile::Door;
+        }
+        self.doors = doors;
+    }
+}
+
+/// The shape of the corridors joining rooms.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Corridor {
+    /// A horizontal and a vertical segment, with the corner chosen at random.
+    #[default]
+    LShaped,
+    /// A straight line, as given by [Point::plot_line], widened at each
+    /// diagonal step so that it can be followed with orthogonal moves.
+    Line,
+}
+
+/// The parameters of [bsp].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BspConfig {
+    /// The smallest width or height of a partition. Partitions are never split
+    /// into parts smaller than this, and are always at least two larger than
+    /// min_room so that each can hold a walled room.
+    pub min_leaf: i32,
+    /// The smallest width or height of a room.
+    pub min_room: i32,
+    /// The maximum number of times space is split, limiting the number of
+    /// rooms to 2 to the power of max_depth.
+    pub max_depth: u32,
+    /// The shape of the corridors joining rooms.
+    pub corridor: Corridor,
+}
+
+impl Default for BspConfig {
+    fn default() -> Self {
+        Self {
+            min_leaf: 8,
+            min_room: 4,
+            max_depth: 6,
+            corridor: Corridor::LShaped,
+        }
+    }
+}
+
+/// Generates a map by recursively splitting it into partitions with
+/// [binary space partitioning](https://en.wikipedia.org/wiki/Binary_space_partitioning),
+/// placing a room in each partition, and joining the two halves of every
+/// split with a corridor. Every room is reachable from every other room.
+///
+/// # Examples
+///
+/// ```
+/// use point::generate::{BspConfig, Tile, bsp};
+/// use point::pathfinding::Neighbourhood;
+/// use point::flood::flood_fill;
+///
+/// let dungeon = bsp(60, 40, &BspConfig::default(), 7);
+///
+/// assert_eq!(dungeon, bsp(60, 40, &BspConfig::default(), 7));
+/// assert!(dungeon.rooms.len() > 1);
+///
+/// let start = dungeon.rooms[0].center();
+/// let reachable = flood_fill(start, Neighbourhood::Four, |p| dungeon.tiles.get(p).is_some_and(|t| t.is_passable()));
+/// assert!(dungeon.rooms.iter().all(|r| reachable.contains(&r.center())));
+/// assert!(dungeon.doors.iter().all(|&d| dungeon.tiles[d] == Tile::Door));
+///
+/// // Rooms too large for the map are never placed.
+/// let huge = BspConfig { min_leaf: i32::MAX, min_room: i32::MAX, ..BspConfig::default() };
+/// assert!(bsp(60, 40, &huge, 7).rooms.is_empty());
+/// ```
+pub fn bsp(width: i32, height: i32, config: &BspConfig, seed: u64) -> Dungeon {
+    let mut dungeon = Dungeon::solid(width, height);
+    let mut rng = Rng::new(seed);
+    let min_room = config.min_room.max(1);
+    let min_leaf = config.min_leaf.max(min_room.saturating_add(2));
+    let bounds = dungeon.tiles.bounds();
+
+    if bounds.width() >= min_room.saturating_add(2) && bounds.height() >= min_room.saturating_add(2)
+    {
+        split_leaf(
+            &mut dungeon,
+            &mut rng,
+            config,
+            bounds,
+            min_leaf,
+            min_room,
+            config.max_depth,
+        );
+    }
+    dungeon.place_doors();
+    dungeon
+}
+
+/// Splits leaf in two and recurses, or places a room in it. Returns the
+/// indices of the rooms placed within leaf.
+fn split_leaf(
+    dungeon: &mut Dungeon,
+    rng: &mut Rng,
+    config: &BspConfig,
+    leaf: Rect,
+    min_leaf: i32,
+    min_room: i32,
+    depth: u32,
+) -> Vec<usize> {
+    let can_split_x = leaf.width() >= min_leaf.saturating_mul(2);
+    let can_split_y = leaf.height() >= min_leaf.saturating_mul(2);
+
+    if depth == 0 || !(can_split_x || can_split_y) {
+        // Leaves are disjoint, so insetting each room by one tile leaves at
+        // least two walls between neighbouring rooms, and keeps rooms off
+        // the outer ring of the map.
+        let space = leaf.inflate(-1);
+        let w = rng.range(min_room, space.width() + 1);
+        let h = rng.range(min_room, space.height() + 1);
+        let x = rng.range(space.min.x, space.max.x - w + 1);
+        let y = rng.range(space.min.y, space.max.y - h + 1);
+        let room = Rect::from_size(Point::new(x, y), w, h);
+
+        dungeon.carve_rect(&room);
+        dungeon.rooms.push(room);
+        return vec![dungeon.rooms.len() - 1];
+    }
+
+    let split_x = match (can_split_x, can_split_y) {
+        (true, false) => true,
+        (false, true) => false,
+        _ if leaf.width() * 4 > leaf.height() * 5 => true,
+        _ if leaf.height() * 4 > leaf.width() * 5 => false,
+        _ => rng.chance(0.5),
+    };
+    let (first, second) = if split_x {
+        let x = rng.range(leaf.min.x + min_leaf, leaf.max.x - min_leaf + 1);
+        leaf.split_at_x(x)
+    } else {
+        let y = rng.range(leaf.min.y + min_leaf, leaf.max.y - min_leaf + 1);
+        leaf.split_at_y(y)
+    }
+    .expect("split position lies strictly inside the leaf");
+
+    let mut rooms = split_leaf(dungeon, rng, config, first, min_leaf, min_room, depth - 1);
+    let other = split_leaf(dungeon, rng, config, second, min_leaf, min_room, depth - 1);
+
+    let a = dungeon.rooms[rooms[rng.range(0, rooms.len() as i32) as usize]].center();
+    let b = dungeon.rooms[other[rng.range(0, other.len() as i32) as usize]].center();
+    dungeon.carve_corridor(a, b, config.corridor, rng);
+
+    rooms.extend(other);
+    rooms
+}
+
+/// The parameters of [scatter_rooms].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoomsConfig {
+    /// The number of times a room is placed at random. Rooms that would
+    /// overlap or touch an existing room are discarded, so fewer rooms than
+    /// this are usually created.
+    pub attempts: u32,
+    /// The smallest width or height of a room.
+    pub min_room: i32,
+    /// The largest width or height of a room.
+    pub max_room: i32,
+    /// The shape of the corridors joining rooms.
+    pub corridor: Corridor,
+}
+
+impl Default for RoomsConfig {
+    fn default() -> Self {
+        Self {
+            attempts: 30,
+            min_room: 4,
+            max_room: 10,
+            corridor: Corridor::LShaped,
+        }
+    }
+}
+
+/// Generates a map by placing rooms at random positions, and joining each
+/// room to the one placed before it with a corridor. Every room is reachable
+/// from every other room.
+///
+/// # Examples
+///
+/// ```
+/// use point::generate::{Corridor, RoomsConfig, scatter_rooms};
+///
+/// let config = RoomsConfig { corridor: Corridor::Line, ..Default::default() };
+/// let dungeon = scatter_rooms(80, 50, &config, 3);
+///
+/// assert!(!dungeon.rooms.is_empty());
+/// for (i, a) in dungeon.rooms.iter().enumerate() {
+///     assert!(dungeon.rooms[i + 1..].iter().all(|b| !a.inflate(1).intersects(b)));
+/// }
+/// ```
+pub fn scatter_rooms(width: i32, height: i32, config: &RoomsConfig, seed: u64) -> Dungeon {
+    let mut dungeon = Dungeon::solid(width, height);
+    let mut rng = Rng::new(seed);
+    let interior = dungeon.interior();
+    let min_room = config.min_room.max(1);
+    let max_room = config.max_room.min(interior.width()).min(interior.height());
+
+    if min_room <= max_room {
+        for _ in 0..config.attempts {
+            let w = rng.range(min_room, max_room + 1);
+            let h = rng.range(min_room, max_room + 1);
+            let x = rng.range(interior.min.x, interior.max.x - w + 1);
+            let y = rng.range(interior.min.y, interior.max.y - h + 1);
+            let room = Rect::from_size(Point::new(x, y), w, h);
+
+            if dungeon.rooms.iter().any(|r| r.inflate(1).intersects(&room)) {
+                continue;
+            }
+            dungeon.carve_rect(&room);
+            if let Some(prev) = dungeon.rooms.last() {
+                let from = prev.center();
+                dungeon.carve_corridor(from, room.center(), config.corridor, &mut rng);
+            }
+            dungeon.rooms.push(room);
+        }
+    }
+    dungeon.place_doors();
+    dungeon
+}
+
+/// Generates a cave-like map by starting in the middle and walking in random
+/// orthogonal steps, turning every tile visited into floor, until the given
+/// fraction of the tiles within the outer wall are floor.
+///
+/// # Examples
+///
+/// ```
+/// use point::generate::{Tile, drunkards_walk};
+///
+/// let dungeon = drunkards_walk(40, 30, 0.4, 11);
+/// let floor = dungeon.tiles.iter().filter(|&&t| t == Tile::Floor).count();
+///
+/// assert!(floor >= (38 * 28) * 4 / 10);
+/// assert!(dungeon.rooms.is_empty());
+/// ```
+pub fn drunkards_walk(width: i32, height: i32, coverage: f64, seed: u64) -> Dungeon {
+    let mut dungeon = Dungeon::solid(width, height);
+    let mut rng = Rng::new(seed);
+    let interior = dungeon.interior();
+    if interior.is_empty() {
+        return dungeon;
+    }
+    let target = (coverage.clamp(0.0, 1.0) * interior.area() as f64).ceil() as i64;
+    let mut pos = interior.center();
+    let mut floor = 0;
+
+    while floor < target {
+        if dungeon.tiles[pos] == Tile::Wall {
+            dungeon.tiles[pos] = Tile::Floor;
+            floor += 1;
+        }
+        let step = pos.adjacent()[rng.range(0, 4) as usize];
+        pos = interior.clamp_point(step);
+    }
+    dungeon
+}
+
+/// The parameters of [caves].
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaveConfig {
+    /// The probability of each tile starting as wall.
+    pub fill: f64,
+    /// The maximum number of generations of smoothing.
+    pub generations: usize,
+}
+
+impl Default for CaveConfig {
+    fn default() -> Self {
+        Self {
+            fill: 0.45,
+            generations: 5,
+        }
+    }
+}
+
+/// Generates a cave by filling the map with random walls, then smoothing it
+/// with the cellular automaton [LifeRule::CAVE], treating everything beyond
+/// the edges as wall. Only the largest connected area of floor is kept, so
+/// every floor tile is reachable from every other.
+///
+/// # Examples
+///
+/// ```
+/// use point::generate::{CaveConfig, Tile, caves};
+/// use point::flood::label_components;
+/// use point::pathfinding::Neighbourhood;
+///
+/// let cave = caves(50, 40, &CaveConfig::default(), 5);
+/// let areas = label_components(&cave.tiles, Neighbourhood::Four, |&t| t == Tile::Floor);
+///
+/// assert_eq!(areas.components.len(), 1);
+/// ```
+pub fn caves(width: i32, height: i32, config: &CaveConfig, seed: u64) -> Dungeon {
+    let mut dungeon = Dungeon::solid(width, height);
+    let mut rng = Rng::new(seed);
+    let interior = dungeon.interior();
+
+    let walls = Grid::from_fn(dungeon.tiles.width(), dungeon.tiles.height(), |p| {
+        !interior.contains(p) || rng.chance(config.fill)
+    });
+    let mut automaton = Automaton::new(walls, Wrap::Clipped, true);
+    automaton.run_until_stable(config.generations, LifeRule::CAVE);
+    let walls = automaton.into_grid();
+
+    let areas = label_components(&walls, Neighbourhood::Four, |&wall| !wall);
+    if let Some(largest) = areas.components.iter().max_by_key(|c| c.points.len()) {
+        // The outer ring starts as wall, and with the boundary beyond it
+        // every tile in it has enough wall neighbours to survive smoothing.
+        for &p in &largest.points {
+            dungeon.tiles[p] = Tile::Floor;
+        }
+    }
+    dungeon
+}
@@ -0,0 +1,410 @@
+//! An owned two dimensional container indexed by [Point].
+use crate::{Point, Rect, Transform};
+use std::{iter, ops, slice};
+
+/// A rectangular grid of values, stored as a flat buffer in row-major order.
+///
+/// Cells are addressed using [Point]s, where x is the column and y is the row.
+/// The conversion between points and indices into the underlying buffer is
+/// handled internally, so callers never need to touch raw indices.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Grid, Point};
+///
+/// let mut grid = Grid::new(3, 2, 0);
+/// grid[Point::new(2, 1)] = 5;
+///
+/// assert_eq!(grid.get(Point::new(2, 1)), Some(&5));
+/// assert_eq!(grid.get(Point::new(3, 1)), None);
+/// assert_eq!(grid.as_slice(), &[0, 0, 0, 0, 0, 5]);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Returns a new grid with the given dimensions, where every cell is
+    /// a clone of value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Returns a new grid with the given dimensions, where each cell is
+    /// initialised by calling f with its position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Grid, Point};
+    ///
+    /// let grid = Grid::from_fn(3, 3, |p| p.x * p.y);
+    ///
+    /// assert_eq!(grid[Point::new(2, 2)], 4);
+    /// assert_eq!(grid[Point::new(1, 2)], 2);
+    /// ```
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(Point) -> T,
+    {
+        let cells = (0..width * height)
+            .map(|i| f(Point::convert_up(i, width)))
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from a row-major buffer of cells. Returns None if the
+    /// length of the buffer is not equal to width * height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Grid, Point};
+    ///
+    /// let grid = Grid::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+    ///
+    /// assert_eq!(grid[Point::new(0, 1)], 3);
+    /// assert!(Grid::from_vec(2, 2, vec![1, 2, 3]).is_none());
+    /// ```
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if cells.len() == width * height {
+            Some(Self {
+                width,
+                height,
+                cells,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of columns in the grid.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows in the grid.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the total number of cells in the grid.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns true if the grid contains no cells.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the rect covering every position in the grid.
+    #[inline]
+    pub const fn bounds(&self) -> Rect {
+        Rect::from_size(Point::ORIGIN, self.width as i32, self.height as i32)
+    }
+
+    /// Returns true if the point lies within the grid.
+    #[inline]
+    pub const fn in_bounds(&self, pos: Point) -> bool {
+        pos.bounds_check(self.width as i32, self.height as i32)
+    }
+
+    /// Returns a reference to the cell at pos, or None if pos is outside
+    /// of the grid.
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        if self.in_bounds(pos) {
+            self.cells.get(pos.convert_down(self.width))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the cell at pos, or None if pos is
+    /// outside of the grid.
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            self.cells.get_mut(pos.convert_down(self.width))
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the cell at pos with value, returning the old value. Returns
+    /// None and drops value if pos is outside of the grid.
+    pub fn set(&mut self, pos: Point, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Sets every cell in the grid to a clone of value.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+
+    /// Returns the cells of the grid as a row-major slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns the cells of the grid as a mutable row-major slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Consumes the grid, returning the row-major buffer of cells.
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// Returns an iterator over every cell in row-major order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Returns an iterator over mutable references to every cell in
+    /// row-major order.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Returns an iterator over every position in the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.len()).map(move |i| Point::convert_up(i, width))
+    }
+
+    /// Returns an iterator over each cell paired with its position, in
+    /// row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Grid, Point};
+    ///
+    /// let grid = Grid::from_vec(2, 2, vec!['a', 'b', 'c', 'd']).unwrap();
+    /// let found = grid.enumerate().find(|&(_, c)| *c == 'c').map(|(p, _)| p);
+    ///
+    /// assert_eq!(found, Some(Point::new(0, 1)));
+    /// ```
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Returns an iterator over mutable references to each cell paired with
+    /// its position, in row-major order.
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    /// Returns the cells in row y as a slice, or None if the row is outside
+    /// of the grid.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cells in row y as a mutable slice, or None if the row is
+    /// outside of the grid.
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        if y < self.height {
+            Some(&mut self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the rows of the grid, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Grid;
+    ///
+    /// let grid = Grid::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let sums: Vec<i32> = grid.rows().map(|row| row.iter().sum()).collect();
+    ///
+    /// assert_eq!(sums, vec![6, 15]);
+    /// ```
+    pub fn rows(&self) -> slice::Chunks<'_, T> {
+        // chunks panics on a chunk size of 0, but any non-zero size yields
+        // nothing for an empty buffer.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Returns an iterator over mutable rows of the grid, from top to bottom.
+    pub fn rows_mut(&mut self) -> slice::ChunksMut<'_, T> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    /// Returns an iterator over the cells in column x from top to bottom, or
+    /// None if the column is outside of the grid.
+    pub fn column(&self, x: usize) -> Option<iter::StepBy<slice::Iter<'_, T>>> {
+        if x < self.width {
+            Some(
+                self.cells
+                    .get(x..)
+                    .unwrap_or_default()
+                    .iter()
+                    .step_by(self.width),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the columns of the grid, from left to right.
+    /// Each column is itself an iterator over its cells from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Grid;
+    ///
+    /// let grid = Grid::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let sums: Vec<i32> = grid.columns().map(|col| col.sum()).collect();
+    ///
+    /// assert_eq!(sums, vec![5, 7, 9]);
+    /// ```
+    pub fn columns(&self) -> impl Iterator<Item = iter::StepBy<slice::Iter<'_, T>>> {
+        (0..self.width).map(move |x| {
+            self.cells
+                .get(x..)
+                .unwrap_or_default()
+                .iter()
+                .step_by(self.width)
+        })
+    }
+
+    /// Returns a new grid of the same dimensions with f applied to each cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns a copy of the grid rotated or reflected by the given
+    /// transform, or None if the transform is not one of the symmetries of a
+    /// square. The translation of the transform is ignored, as the new grid
+    /// always starts at the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Grid, Point, Transform};
+    ///
+    /// let grid = Grid::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let turned = grid.transform(Transform::ROTATE_90_CW).unwrap();
+    ///
+    /// assert_eq!((turned.width(), turned.height()), (2, 3));
+    /// assert_eq!(turned.as_slice(), &[3, 6, 2, 5, 1, 4]);
+    /// assert!(grid.transform(Transform::scale(2, 2)).is_none());
+    /// ```
+    pub fn transform(&self, transform: Transform) -> Option<Self>
+    where
+        T: Clone,
+    {
+        let linear = Transform::linear(transform.matrix);
+        let inverse = linear.inverse().filter(|_| linear.is_symmetry())?;
+        let bounds = linear.apply_rect(&self.bounds());
+
+        Some(Self::from_fn(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            |p| self[inverse.apply(p + bounds.min)].clone(),
+        ))
+    }
+}
+
+impl<T> ops::Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// Returns a reference to the cell at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside of the grid.
+    fn index(&self, pos: Point) -> &Self::Output {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {pos} is out of bounds for grid of size {}x{}",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> ops::IndexMut<Point> for Grid<T> {
+    /// Returns a mutable reference to the cell at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside of the grid.
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("position {pos} is out of bounds for grid of size {width}x{height}"),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for Grid<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
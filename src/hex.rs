@@ -0,0 +1,583 @@
+//! Hexagonal grid co-ordinates.
+//!
+//! Hexes are stored in axial co-ordinates, q and r, with the third cube
+//! co-ordinate s derived from them so that q + r + s = 0. Axial co-ordinates
+//! make distances, lines and rotations simple, but maps are usually stored in
+//! rectangular arrays, so conversions to and from the four common [Offset]
+//! layouts are also provided.
+//!
+//! The neighbours of a hex are numbered from 0 to 5, as given by
+//! [Hex::DIRECTIONS]. With pointy topped hexes laid out by a [Layout] with a
+//! positive size, direction 0 points east and the directions go round
+//! clockwise, with the y axis pointing upwards as for [Point]. Give the layout
+//! a negative y size to draw onto a screen whose y axis points downwards.
+use crate::{Point, Vec2};
+use std::{fmt, iter::FusedIterator, ops};
+
+/// The position of a hex on a hexagonal grid, in axial co-ordinates.
+///
+/// # Examples
+///
+/// ```
+/// use point::hex::Hex;
+///
+/// let a = Hex::new(0, 0);
+/// let b = Hex::new(2, -3);
+///
+/// assert_eq!(b.s(), 1);
+/// assert_eq!(a.dist(b), 3);
+/// assert_eq!(a.neighbours().iter().filter(|n| n.dist(b) == 2).count(), 2);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hex {
+    /// Q co-ordinate, increasing towards direction 0.
+    pub q: i32,
+    /// R co-ordinate, increasing towards direction 1.
+    pub r: i32,
+}
+
+impl Hex {
+    /// The hex at (0, 0).
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    /// The offsets to each of the six neighbours of a hex, going round
+    /// clockwise.
+    pub const DIRECTIONS: [Self; 6] = [
+        Self::new(1, 0),
+        Self::new(0, 1),
+        Self::new(-1, 1),
+        Self::new(-1, 0),
+        Self::new(0, -1),
+        Self::new(1, -1),
+    ];
+
+    /// Returns a new hex with the given axial co-ordinates.
+    #[inline(always)]
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// Returns the hex with the given cube co-ordinates, or None if they do
+    /// not sum to 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::hex::Hex;
+    ///
+    /// assert_eq!(Hex::from_cube(1, 2, -3), Some(Hex::new(1, 2)));
+    /// assert_eq!(Hex::from_cube(1, 2, 3), None);
+    /// ```
+    pub const fn from_cube(q: i32, r: i32, s: i32) -> Option<Self> {
+        if q + r + s == 0 {
+            Some(Self::new(q, r))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the third cube co-ordinate, equal to -q - r.
+    #[inline]
+    pub const fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Returns the cube co-ordinates of the hex as `[q, r, s]`.
+    #[inline]
+    pub const fn to_cube(self) -> [i32; 3] {
+        [self.q, self.r, self.s()]
+    }
+
+    /// Returns the hex one step away from self in the given direction. The
+    /// direction is taken modulo 6.
+    #[inline]
+    pub const fn neighbour(self, direction: usize) -> Self {
+        let offset = Self::DIRECTIONS[direction % 6];
+        Self::new(self.q + offset.q, self.r + offset.r)
+    }
+
+    /// Returns the six hexes adjacent to self, in the same order as
+    /// [Hex::DIRECTIONS].
+    pub const fn neighbours(self) -> [Self; 6] {
+        let mut neighbours = Self::DIRECTIONS;
+        let mut i = 0;
+        while i < 6 {
+            neighbours[i] = self.neighbour(i);
+            i += 1;
+        }
+        neighbours
+    }
+
+    /// Returns the number of steps between self and the origin.
+    #[inline]
+    pub const fn length(self) -> i32 {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    /// Returns the number of steps between self and other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::hex::Hex;
+    ///
+    /// assert_eq!(Hex::new(-1, 3).dist(Hex::new(2, 1)), 3);
+    /// ```
+    #[inline]
+    pub const fn dist(self, other: Self) -> i32 {
+        Self::new(self.q - other.q, self.r - other.r).length()
+    }
+
+    /// Returns the hex rotated about the origin by 60 degrees clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::hex::Hex;
+    ///
+    /// let h = Hex::DIRECTIONS[0] * 2;
+    ///
+    /// assert_eq!(h.rotate_60_cw(), Hex::DIRECTIONS[1] * 2);
+    /// assert_eq!(h.rotate_60_cw().rotate_60_acw(), h);
+    /// ```
+    #[inline]
+    pub const fn rotate_60_cw(self) -> Self {
+        Self::new(-self.r, -self.s())
+    }
+
+    /// Returns the hex rotated about the origin by 60 degrees anti-clockwise.
+    #[inline]
+    pub const fn rotate_60_acw(self) -> Self {
+        Self::new(-self.s(), -self.q)
+    }
+
+    /// Returns the hex rotated about centre by 60 degrees clockwise the given
+    /// number of times. Negative turns rotate anti-clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::hex::Hex;
+    ///
+    /// let centre = Hex::new(3, -1);
+    /// let h = Hex::new(4, -1);
+    ///
+    /// assert_eq!(h.rotate_about(centre, 3), Hex::new(2, -1));
+    /// assert_eq!(h.rotate_about(centre, -1), h.rotate_about(centre, 5));
+    /// ```
+    pub const fn rotate_about(self, centre: Self, turns: i32) -> Self {
+        let mut offset = Self::new(self.q - centre.q, self.r - centre.r);
+        let mut i = 0;
+        while i < turns.rem_euclid(6) {
+            offset = offset.rotate_60_cw();
+            i += 1;
+        }
+        Self::new(centre.q + offset.q, centre.r + offset.r)
+    }
+
+    /// Returns an iterator over the hexes on the straight line between src
+    /// and dest, including both of them. Consecutive hexes are always
+    /// adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::hex::Hex;
+    ///
+    /// let line: Vec<Hex> = Hex::line(Hex::new(0, 0), Hex::new(3, -1)).collect();
+    ///
+    /// assert_eq!(line, vec![Hex::new(0, 0), Hex::new(1, 0), Hex::new(2, -1), Hex::new(3, -1)]);
+    /// assert!(line.windows(2).all(|w| w[0].dist(w[1]) == 1));
+    /// ```
+    pub fn line(
+        src: Self,
+        dest: Self,
+    ) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        let steps = src.dist(dest) as u32;
+        // Nudging the ends stops points exactly on an edge between two hexes
+        // from rounding inconsistently.
+        let start = FractionalHex::new(src.q as f64 + 1e-6, src.r as f64 + 2e-6);
+        let end = FractionalHex::new(dest.q as f64 + 1e-6, dest.r as f64 + 2e-6);
+
+        (0..steps + 1).map(move |i| {
+            if steps == 0 {
+                src
+            } else {
+                start.lerp(end, i as f64 / steps as f64).round()
+            }
+        })
+    }
+
+    /// Returns an iterator over the hexes exactly radius steps away from
+    /// centre. A radius of 0 gives just centre.
+    ///
+    /// # Panics
+    ///
+    /// Panics if radius is greater than i32::MAX, as such a ring cannot be
+    /// represented in i32 co-ordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::hex::Hex;
+    ///
+    /// let centre = Hex::new(1, 1);
+    /// let ring: Vec<Hex> = Hex::ring(centre, 2).collect();
+    ///
+    /// assert_eq!(ring.len(), 12);
+    /// assert!(ring.iter().all(|h| h.dist(centre) == 2));
+    /// assert_eq!(Hex::ring(centre, 0).collect::<Vec<_>>(), vec![centre]);
+    ///
+    /// let ring = Hex::ring(Hex::ORIGIN, i32::MAX as u32);
+    /// assert_eq!(ring.len(), usize::try_from(6 * i32::MAX as u64).unwrap_or(usize::MAX));
+    /// ```
+    pub fn ring(centre: Self, radius: u32) -> HexRing {
+        assert!(radius <= i32::MAX as u32, "radius is too large");
+        HexRing {
+            centre,
+            radius: radius as i32,
+            index: 0,
+            len: if radius == 0 { 1 } else { 6 * radius as u64 },
+        }
+    }
+
+    /// Returns an iterator over every hex no more than radius steps away from
+    /// centre, in rings of increasing radius, starting with centre itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::hex::Hex;
+    ///
+    /// let spiral: Vec<Hex> = Hex::spiral(Hex::ORIGIN, 2).collect();
+    ///
+    /// assert_eq!(spiral.len(), 19);
+    /// assert_eq!(spiral[0], Hex::ORIGIN);
+    /// assert!(spiral.windows(2).all(|w| w[0].length() <= w[1].length()));
+    /// ```
+    pub fn spiral(centre: Self, radius: u32) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |r| Self::ring(centre, r))
+    }
+
+    /// Returns the position of the hex in a rectangular array using the given
+    /// offset layout, where x is the column and y is the row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::hex::{Hex, Offset};
+    ///
+    /// let h = Hex::new(1, 3);
+    ///
+    /// assert_eq!(h.to_offset(Offset::OddR), Point::new(2, 3));
+    /// assert_eq!(h.to_offset(Offset::EvenR), Point::new(3, 3));
+    /// assert_eq!(Hex::from_offset(Point::new(2, 3), Offset::OddR), h);
+    /// ```
+    pub const fn to_offset(self, offset: Offset) -> Point {
+        let (q, r) = (self.q, self.r);
+        match offset {
+            Offset::OddR => Point::new(q + (r - (r & 1)) / 2, r),
+            Offset::EvenR => Point::new(q + (r + (r & 1)) / 2, r),
+            Offset::OddQ => Point::new(q, r + (q - (q & 1)) / 2),
+            Offset::EvenQ => Point::new(q, r + (q + (q & 1)) / 2),
+        }
+    }
+
+    /// Performs the inverse of [Hex::to_offset]; returns the hex at the given
+    /// column and row of a rectangular array using the given offset layout.
+    pub const fn from_offset(pos: Point, offset: Offset) -> Self {
+        let (x, y) = (pos.x, pos.y);
+        match offset {
+            Offset::OddR => Self::new(x - (y - (y & 1)) / 2, y),
+            Offset::EvenR => Self::new(x - (y + (y & 1)) / 2, y),
+            Offset::OddQ => Self::new(x, y - (x - (x & 1)) / 2),
+            Offset::EvenQ => Self::new(x, y - (x + (x & 1)) / 2),
+        }
+    }
+}
+
+impl ops::Add for Hex {
+    type Output = Self;
+
+    /// Returns a new hex containing the sum of the hexes' co-ordinates.
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl ops::Sub for Hex {
+    type Output = Self;
+
+    /// Returns a new hex containing the difference of the hexes' co-ordinates.
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl ops::Neg for Hex {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl ops::Mul<i32> for Hex {
+    type Output = Self;
+
+    /// Returns a new hex containing each co-ordinate multiplied by the given
+    /// multiplier.
+    fn mul(self, other: i32) -> Self::Output {
+        Self::new(self.q * other, self.r * other)
+    }
+}
+
+impl fmt::Display for Hex {
+    /// Formats the hex as its cube co-ordinates, `(q, r, s)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s())
+    }
+}
+
+impl From<Point> for Hex {
+    /// Interprets the x and y co-ordinates of the point as q and r.
+    fn from(val: Point) -> Self {
+        Self::new(val.x, val.y)
+    }
+}
+
+impl From<Hex> for Point {
+    /// Returns a point with the axial co-ordinates of the hex as x and y.
+    fn from(val: Hex) -> Self {
+        Point::new(val.q, val.r)
+    }
+}
+
+/// A position on a hexagonal grid that need not be at the centre of a hex, in
+/// axial co-ordinates.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FractionalHex {
+    /// Q co-ordinate.
+    pub q: f64,
+    /// R co-ordinate.
+    pub r: f64,
+}
+
+impl FractionalHex {
+    /// Returns a new fractional hex with the given axial co-ordinates.
+    #[inline(always)]
+    pub const fn new(q: f64, r: f64) -> Self {
+        Self { q, r }
+    }
+
+    /// Returns the third cube co-ordinate, equal to -q - r.
+    #[inline]
+    pub fn s(self) -> f64 {
+        -self.q - self.r
+    }
+
+    /// Linearly interpolates between self and other. A t of 0 returns self,
+    /// and a t of 1 returns other.
+    #[inline]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        Self::new(
+            self.q + (other.q - self.q) * t,
+            self.r + (other.r - self.r) * t,
+        )
+    }
+
+    /// Returns the hex containing this position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::hex::{FractionalHex, Hex};
+    ///
+    /// assert_eq!(FractionalHex::new(0.4, 0.4).round(), Hex::new(0, 1));
+    /// assert_eq!(FractionalHex::new(-0.2, 0.1).round(), Hex::ORIGIN);
+    /// ```
+    pub fn round(self) -> Hex {
+        let (q, r, s) = (self.q.round(), self.r.round(), self.s().round());
+        let dq = (q - self.q).abs();
+        let dr = (r - self.r).abs();
+        let ds = (s - self.s()).abs();
+
+        // Rounding each co-ordinate separately may break q + r + s = 0, so
+        // the one that was rounded furthest is recalculated from the others.
+        if dq > dr && dq > ds {
+            Hex::new((-r - s) as i32, r as i32)
+        } else if dr > ds {
+            Hex::new(q as i32, (-q - s) as i32)
+        } else {
+            Hex::new(q as i32, r as i32)
+        }
+    }
+}
+
+impl From<Hex> for FractionalHex {
+    fn from(val: Hex) -> Self {
+        Self::new(val.q.into(), val.r.into())
+    }
+}
+
+/// An iterator over the hexes in a ring, created by [Hex::ring].
+#[derive(Clone, Debug)]
+pub struct HexRing {
+    centre: Hex,
+    radius: i32,
+    index: u64,
+    len: u64,
+}
+
+impl Iterator for HexRing {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        if self.radius == 0 {
+            return Some(self.centre);
+        }
+        // Each side of the ring starts at a corner and walks in the direction
+        // two steps clockwise from the one pointing at that corner.
+        let side = (index / self.radius as u64) as usize;
+        let step = (index % self.radius as u64) as i32;
+        let corner = Hex::DIRECTIONS[(side + 4) % 6] * self.radius;
+
+        Some(self.centre + corner + Hex::DIRECTIONS[side] * step)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.len - self.index) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, Some(usize::MAX)),
+        }
+    }
+}
+
+impl ExactSizeIterator for HexRing {}
+
+impl FusedIterator for HexRing {}
+
+/// The ways of storing a hexagonal map in a rectangular array, where every
+/// other row or column is shifted by half a hex.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Offset {
+    /// Rows of pointy topped hexes, with odd rows shifted towards positive x.
+    OddR,
+    /// Rows of pointy topped hexes, with even rows shifted towards positive x.
+    EvenR,
+    /// Columns of flat topped hexes, with odd columns shifted towards
+    /// positive y.
+    OddQ,
+    /// Columns of flat topped hexes, with even columns shifted towards
+    /// positive y.
+    EvenQ,
+}
+
+/// Which way up the hexes are drawn.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// Hexes have a corner at the top, and form horizontal rows.
+    Pointy,
+    /// Hexes have an edge at the top, and form vertical columns.
+    Flat,
+}
+
+/// The mapping between hexes and pixel co-ordinates.
+///
+/// # Examples
+///
+/// ```
+/// use point::Vec2;
+/// use point::hex::{Hex, Layout, Orientation};
+///
+/// let layout = Layout::new(Orientation::Pointy, Vec2::new(10.0, 10.0), Vec2::new(100.0, 50.0));
+/// let h = Hex::new(2, -1);
+///
+/// assert_eq!(layout.from_pixel(layout.to_pixel(h)), h);
+/// assert_eq!(layout.from_pixel(layout.to_pixel(h) + Vec2::new(4.0, -3.0)), h);
+/// assert_eq!(layout.to_pixel(Hex::ORIGIN), Vec2::new(100.0, 50.0));
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layout {
+    /// Which way up the hexes are drawn.
+    pub orientation: Orientation,
+    /// The distance from the centre of a hex to each of its corners, along
+    /// each axis. A negative y size flips the map vertically.
+    pub size: Vec2,
+    /// The pixel position of the centre of the hex at the origin.
+    pub origin: Vec2,
+}
+
+impl Layout {
+    /// Returns a new layout with the given orientation, size and origin.
+    #[inline]
+    pub const fn new(orientation: Orientation, size: Vec2, origin: Vec2) -> Self {
+        Self {
+            orientation,
+            size,
+            origin,
+        }
+    }
+
+    /// Returns the pixel position of the centre of the hex.
+    pub fn to_pixel(&self, hex: Hex) -> Vec2 {
+        let (q, r) = (hex.q as f64, hex.r as f64);
+        let sqrt_3 = 3f64.sqrt();
+        let (x, y) = match self.orientation {
+            Orientation::Pointy => (sqrt_3 * q + sqrt_3 / 2.0 * r, -1.5 * r),
+            Orientation::Flat => (1.5 * q, -(sqrt_3 / 2.0 * q + sqrt_3 * r)),
+        };
+
+        Vec2::new(
+            x * self.size.x + self.origin.x,
+            y * self.size.y + self.origin.y,
+        )
+    }
+
+    /// Returns the fractional position in hex co-ordinates of the pixel.
+    pub fn from_pixel_fractional(&self, pixel: Vec2) -> FractionalHex {
+        let x = (pixel.x - self.origin.x) / self.size.x;
+        let y = (pixel.y - self.origin.y) / self.size.y;
+        let sqrt_3 = 3f64.sqrt();
+
+        match self.orientation {
+            Orientation::Pointy => FractionalHex::new(sqrt_3 / 3.0 * x + y / 3.0, -2.0 / 3.0 * y),
+            Orientation::Flat => FractionalHex::new(2.0 / 3.0 * x, -x / 3.0 - sqrt_3 / 3.0 * y),
+        }
+    }
+
+    /// Returns the hex containing the pixel.
+    #[inline]
+    pub fn from_pixel(&self, pixel: Vec2) -> Hex {
+        self.from_pixel_fractional(pixel).round()
+    }
+
+    /// Returns the pixel positions of the six corners of the hex, going round
+    /// clockwise.
+    pub fn corners(&self, hex: Hex) -> [Vec2; 6] {
+        let centre = self.to_pixel(hex);
+        let start = match self.orientation {
+            Orientation::Pointy => 30.0f64,
+            Orientation::Flat => 0.0,
+        };
+
+        std::array::from_fn(|i| {
+            let angle = (start - 60.0 * i as f64).to_radians();
+            Vec2::new(
+                centre.x + self.size.x * angle.cos(),
+                centre.y + self.size.y * angle.sin(),
+            )
+        })
+    }
+}
@@ -2,78 +2,235 @@
 //! represnting a position, such as on a grid or in a 2D array.
 use std::{fmt, ops};
 
+mod adjacent;
+pub mod automaton;
+mod direction;
+pub mod flood;
+pub mod fov;
+pub mod generate;
+mod grid;
+pub mod hex;
+mod line;
+pub mod metric;
+pub mod noise;
+mod parse;
+pub mod pathfinding;
+mod point3;
+pub mod polygon;
+mod rect;
+mod rng;
+pub mod scalar;
+#[cfg(feature = "serde")]
+pub mod serialize;
+mod shape;
+mod spatial;
+pub mod topology;
+mod transform;
+
+pub use adjacent::InBounds;
+pub use direction::{Direction, Direction8, TryFromPointError};
+pub use grid::Grid;
+pub use line::{GridTraversal, LineIter, SupercoverIter, ThickLineIter};
+pub use parse::ParsePointError;
+pub use point3::{Axis, Line3Iter, Point3};
+pub use rect::{Perimeter, Rect, RectPoints};
+pub use rng::Rng;
+use scalar::{Float, Integer, Scalar, Signed};
+pub use shape::{ArcIter, CircleIter, DiskIter, DiskShape};
+pub use spatial::{QuadTree, SpatialHash};
+pub use transform::Transform;
+
 /// A 2D co-ordinate.
+///
+/// The co-ordinates may be of any primitive numeric type, defaulting to i32.
+/// Methods that only make sense for some types are only available when the
+/// co-ordinates implement the relevant trait from [scalar]; for example,
+/// rotation with [Rotate] requires [Signed] co-ordinates, and [Point::normalize] requires
+/// [Float] co-ordinates. Grid related methods, such as
+/// [Point::adjacent] and [Point::convert_down], are only available
+/// for i32 co-ordinates.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, Rotate};
+///
+/// let world: Point<i64> = Point::new(1 << 40, 3);
+/// let tile: Point<u16> = Point::new(4, 7);
+/// let sub_tile: Point<f32> = Point::new(0.5, 0.25);
+///
+/// assert_eq!(world.rotate_90_cw(), Point::new(3, -(1 << 40)));
+/// assert_eq!(tile.manhattan_dist(Point::new(6, 2)), 7);
+/// assert_eq!(sub_tile * 2.0, Point::new(1.0, 0.5));
+/// ```
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
-pub struct Point {
+pub struct Point<T = i32> {
     /// X co-ordinate
-    pub x: i32,
+    pub x: T,
     /// Y co-ordinate
-    pub y: i32,
+    pub y: T,
 }
 
-impl Point {
+impl<T: Scalar> Point<T> {
     /// The point at (0, 0).
-    pub const ORIGIN: Self = Self { x: 0, y: 0 };
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
 
     /// Return a new point instance with given x and y positions.
     #[inline(always)]
-    pub const fn new(x: i32, y: i32) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
-    /// Returns a vector of points wrapped in options that would be orthogonally adjacent to the point.
-    /// Takes a maximum x and y co-ordinate, which the returned points will not
-    /// exceed or equal. They will also not be less than 0.
-    pub fn get_adjacent(&self, max_x: i32, max_y: i32) -> Vec<Option<Self>> {
-        let mut points: Vec<Option<Self>> = Vec::new();
-        for p in self.get_all_adjacent() {
-            if p.bounds_check(max_x, max_y) {
-                points.push(Some(p))
-            } else {
-                points.push(None)
-            }
-        }
-        points
+    /// Returns the Euclidean distance between self and other,
+    /// using the pythagorean theorem.
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p1 = Point::new(3, 0);
+    /// let p2 = Point::new(11, 6);
+    ///
+    /// // 8^2 + 6^2 = 100
+    /// // sqrt(100) = 10
+    /// assert_eq!(p1.dist(p2), 10.0f64);
+    ///
+    /// // The distance never overflows, even between the most distant points.
+    /// let (min, max) = (Point::new(i32::MIN, 0), Point::new(i32::MAX, 0));
+    /// assert_eq!(min.dist(max), u32::MAX as f64);
+    /// ```
+    #[inline(always)]
+    pub fn dist(self, other: Self) -> f64 {
+        let dx = self.x.abs_difference_f64(other.x);
+        let dy = self.y.abs_difference_f64(other.y);
+        (dx * dx + dy * dy).sqrt()
     }
 
-    /// Returns every point with a distance of 1 away from the point.
-    /// Does not include points with non integer co_ordinates.
+    /// Returns the squared Euclidean distance between self and other.
+    /// This is more performant than dist as the square root
+    /// step is skipped.
+    ///
+    /// For i32 co-ordinates, the result overflows for points more than roughly
+    /// 46,000 apart. Use [Point::dist_squared_wide] if the points may be
+    /// further apart.
     ///
     /// # Examples
     ///
     /// ```
     /// use point::Point;
     ///
-    /// let mut p = Point::new(0, 0);
+    /// let p1 = Point::new(0, 0);
+    /// let p2 = Point::new(2, 1);
     ///
-    /// let expected: Vec<Point> = vec![(1, 0), (0, -1), (-1, 0), (0, 1)].into_iter().map(Point::from).collect();
-    /// assert_eq!(p.get_all_adjacent(), expected);
+    /// // 2^2 + 1^2 = 5
+    /// assert_eq!(p1.dist_squared(p2), 5);
     /// ```
-    pub fn get_all_adjacent(&self) -> Vec<Self> {
-        let mut points = Vec::new();
-        let mut offset = Point { x: 1, y: 0 };
-        for _ in 0..4 {
-            let new_point = *self + offset;
-            points.push(new_point);
-            offset.rotate_90_cw_ip();
-        }
-        points
+    #[inline]
+    pub fn dist_squared(self, other: Self) -> T {
+        let dx = self.x.abs_difference(other.x);
+        let dy = self.y.abs_difference(other.y);
+        dx * dx + dy * dy
     }
 
-    /// The same as get_all_adjacent, but also returns diagonally adjacent
-    /// points.
-    pub fn get_all_adjacent_diagonal(&self) -> Vec<Self> {
-        let mut points = Vec::new();
-        for x in -1..=1 {
-            for y in -1..=1 {
-                if x != 0 || y != 0 {
-                    points.push(Self::new(self.x + x, self.y + y));
-                }
-            }
-        }
-        points
+    /// Returns the manhattan distance between self and other.
+    ///
+    /// The result saturates at the largest value of T if it is too large to
+    /// represent. For i32 co-ordinates, use [Point::manhattan_dist_wide] if
+    /// the points may be further apart than i32::MAX.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p1 = Point::new(0, 0);
+    /// let p2 = Point::new(2, 1);
+    ///
+    /// // 2-0 = 2, 1-0 = 1, 1+2=3
+    /// assert_eq!(p1.manhattan_dist(p2), 3);
+    ///
+    /// let (min, max) = (Point::new(i32::MIN, i32::MIN), Point::new(i32::MAX, i32::MAX));
+    /// assert_eq!(min.manhattan_dist(max), i32::MAX);
+    /// ```
+    #[inline]
+    pub fn manhattan_dist(self, other: Self) -> T {
+        self.x
+            .abs_difference(other.x)
+            .saturating_add(self.y.abs_difference(other.y))
+    }
+
+    /// Returns the Chebyshev distance between self and other; the larger of
+    /// the differences between their x and y co-ordinates. This is the number
+    /// of moves between them when diagonal moves are allowed, as with
+    /// [Point::adjacent_diagonal].
+    ///
+    /// As with manhattan_dist, the result saturates at the largest value of T.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p1 = Point::new(0, 0);
+    /// let p2 = Point::new(2, -5);
+    ///
+    /// assert_eq!(p1.chebyshev_dist(p2), 5);
+    /// ```
+    #[inline]
+    pub fn chebyshev_dist(self, other: Self) -> T {
+        let dx = self.x.abs_difference(other.x);
+        let dy = self.y.abs_difference(other.y);
+        if dx > dy { dx } else { dy }
+    }
+
+    /// Returns the octile distance between self and other; the length of the
+    /// shortest path between them made of orthogonal moves of length 1 and
+    /// diagonal moves of length √2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p1 = Point::new(0, 0);
+    /// let p2 = Point::new(3, 1);
+    ///
+    /// // Two orthogonal moves and one diagonal move.
+    /// assert_eq!(p1.octile_dist(p2), 2.0 + std::f64::consts::SQRT_2);
+    ///
+    /// let (min, max) = (Point::new(i32::MIN, 0), Point::new(i32::MAX, 0));
+    /// assert_eq!(min.octile_dist(max), u32::MAX as f64);
+    /// ```
+    #[inline]
+    pub fn octile_dist(self, other: Self) -> f64 {
+        let dx = self.x.abs_difference_f64(other.x);
+        let dy = self.y.abs_difference_f64(other.y);
+        dx.max(dy) + (std::f64::consts::SQRT_2 - 1.0) * dx.min(dy)
     }
 
+    /// Returns self ⋅ other; that is, the [dot product](https://en.wikipedia.org/wiki/Dot_product), interpreting
+    /// self and other as 2D vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p1 = Point::new(2, 3);
+    /// let p2 = Point::new(-4, 1);
+    ///
+    /// // 2 * -4 + 3 * 1 = -5
+    /// assert_eq!(p1.dot(p2), -5);
+    /// ```
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl Point {
     /// Rotates the co-ordinate in place about the origin by 90 degrees clockwise.
     ///
     /// # Examples
@@ -108,6 +265,10 @@ impl Point {
     /// let mut p = Point::new(1, 0);
     ///
     /// assert_eq!(Point::new(0, -1), p.rotate_90_cw());
+    ///
+    /// // Rotations of points with i32 co-ordinates can be used in constants.
+    /// const SOUTH: Point = Point::new(1, 0).rotate_90_cw();
+    /// assert_eq!(SOUTH, Point::new(0, -1));
     /// ```
     #[inline]
     pub const fn rotate_90_cw(&self) -> Self {
@@ -177,7 +338,7 @@ impl Point {
     }
 
     /// Returns the co-ordinate rotated about the origin by 180 degrees.
-    /// Equivalent to [Point::neg] if it was `const`.
+    /// Equivalent to [Neg::neg](ops::Neg::neg).
     ///
     /// # Examples
     ///
@@ -207,14 +368,388 @@ impl Point {
     /// ```
     #[inline]
     pub const fn rotate_180_ip(&mut self) {
+        (self.x, self.y) = (-self.x, -self.y);
+    }
+}
+
+/// Rotations about the origin by multiples of 90 degrees, for points with
+/// any [Signed] co-ordinates.
+///
+/// Points with i32 co-ordinates also have inherent const versions of these
+/// methods, which are used in preference to the trait's.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, Rotate, Vec2};
+///
+/// let mut v = Vec2::new(1.5, 0.0);
+/// v.rotate_90_acw_ip();
+///
+/// assert_eq!(v, Vec2::new(0.0, 1.5));
+/// assert_eq!(Point::<i8>::new(2, 1).rotate_180(), Point::new(-2, -1));
+/// ```
+pub trait Rotate: Sized {
+    /// Returns the point rotated about the origin by 90 degrees clockwise.
+    fn rotate_90_cw(&self) -> Self;
+
+    /// Returns the point rotated about the origin by 90 degrees anti-clockwise.
+    fn rotate_90_acw(&self) -> Self;
+
+    /// Returns the point rotated about the origin by 180 degrees.
+    fn rotate_180(&self) -> Self;
+
+    /// Rotates the point in place about the origin by 90 degrees clockwise.
+    #[inline]
+    fn rotate_90_cw_ip(&mut self) {
+        *self = self.rotate_90_cw();
+    }
+
+    /// Rotates the point in place about the origin by 90 degrees anti-clockwise.
+    #[inline]
+    fn rotate_90_acw_ip(&mut self) {
+        *self = self.rotate_90_acw();
+    }
+
+    /// Rotates the point in place about the origin by 180 degrees.
+    #[inline]
+    fn rotate_180_ip(&mut self) {
         *self = self.rotate_180();
     }
+}
+
+impl<T: Signed> Rotate for Point<T> {
+    #[inline]
+    fn rotate_90_cw(&self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+
+    #[inline]
+    fn rotate_90_acw(&self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    #[inline]
+    fn rotate_180(&self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Float> Point<T> {
+    /// Returns the length of the point interpreted as a vector; that is,
+    /// its Euclidean distance from the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(3.0, -4.0);
+    ///
+    /// assert_eq!(p.length(), 5.0);
+    /// ```
+    #[inline]
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the point scaled to have a length of 1, interpreting it as a
+    /// vector. Normalizing the origin results in NaN co-ordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(0.0f32, -2.5);
+    ///
+    /// assert_eq!(p.normalize(), Point::new(0.0, -1.0));
+    /// ```
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    /// Linearly interpolates between self and other. A t of 0 returns self,
+    /// and a t of 1 returns other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p1 = Point::new(1.0, 2.0);
+    /// let p2 = Point::new(3.0, -2.0);
+    ///
+    /// assert_eq!(p1.lerp(p2, 0.25), Point::new(1.5, 1.0));
+    /// ```
+    #[inline]
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Returns the angle of the point interpreted as a vector, in radians
+    /// anti-clockwise from the positive x axis. The result is in the range
+    /// [-π, π].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Vec2;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// assert_eq!(Vec2::new(1.0, 0.0).angle(), 0.0);
+    /// assert_eq!(Vec2::new(0.0, 3.0).angle(), FRAC_PI_2);
+    /// ```
+    #[inline]
+    pub fn angle(self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    /// Returns the projection of self onto other; that is, the component of
+    /// self pointing in the same direction as other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Vec2;
+    ///
+    /// let v = Vec2::new(3.0, 4.0);
+    ///
+    /// assert_eq!(v.project(Vec2::new(2.0, 0.0)), Vec2::new(3.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn project(self, other: Self) -> Self {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Returns self reflected off a surface with the given normal, which is
+    /// expected to have a length of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Vec2;
+    ///
+    /// let v = Vec2::new(2.0, -1.0);
+    /// let floor = Vec2::new(0.0, 1.0);
+    ///
+    /// assert_eq!(v.reflect(floor), Vec2::new(2.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn reflect(self, normal: Self) -> Self {
+        self - normal * ((T::ONE + T::ONE) * self.dot(normal))
+    }
+
+    /// Returns the vector perpendicular to self with the same length, rotated
+    /// 90 degrees anti-clockwise. Equivalent to [Point::rotate_90_acw].
+    #[inline]
+    pub fn perpendicular(self) -> Self {
+        self.rotate_90_acw()
+    }
+
+    /// Converts the point into a point with i32 co-ordinates, rounding each
+    /// co-ordinate as specified. Co-ordinates outside of the range of i32
+    /// saturate, and NaN becomes 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rounding, Vec2};
+    ///
+    /// let v = Vec2::new(1.5, -2.5);
+    ///
+    /// assert_eq!(v.to_point(Rounding::Floor), Point::new(1, -3));
+    /// assert_eq!(v.to_point(Rounding::Ceil), Point::new(2, -2));
+    /// assert_eq!(v.to_point(Rounding::Round), Point::new(2, -3));
+    /// assert_eq!(v.to_point(Rounding::Truncate), Point::new(1, -2));
+    /// ```
+    pub fn to_point(self, rounding: Rounding) -> Point {
+        Point::new(
+            rounding.apply(self.x).to_f64() as i32,
+            rounding.apply(self.y).to_f64() as i32,
+        )
+    }
+}
+
+/// A point with f64 co-ordinates, for representing fractional positions and
+/// directions.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, Rounding, Vec2};
+///
+/// let tile = Point::new(3, 4);
+/// let pos = Vec2::from(tile) + Vec2::new(0.5, 0.5);
+///
+/// assert_eq!(pos.to_point(Rounding::Floor), tile);
+/// ```
+pub type Vec2 = Point<f64>;
+
+/// The rounding applied to each co-ordinate when converting a point with
+/// floating point co-ordinates into one with integer co-ordinates.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest integer, rounding half-way cases away from zero.
+    Round,
+    /// Round towards zero.
+    Truncate,
+}
+
+impl Rounding {
+    /// Returns val rounded to an integer using this rounding.
+    pub fn apply<T: Float>(self, val: T) -> T {
+        match self {
+            Self::Floor => val.floor(),
+            Self::Ceil => val.ceil(),
+            Self::Round => val.round(),
+            Self::Truncate => val.trunc(),
+        }
+    }
+}
+
+impl<T: Integer> Point<T> {
+    /// Returns an iterator over all the points on the line between src and dest.
+    /// The points are those chosen by [Bresenham's Line Algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm),
+    /// with ties always broken in the same way regardless of the direction
+    /// of the line, so reversing a line gives the same points as plotting it
+    /// the other way.
+    /// Note that src is included in the line, but dest is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let line: Vec<Point<u8>> = Point::plot_line(Point::new(0, 0), Point::new(4, 2)).collect();
+    ///
+    /// let expected: Vec<Point<u8>> = vec![(0, 0), (1, 1), (2, 1), (3, 2)].into_iter().map(Point::from).collect();
+    /// assert_eq!(line, expected);
+    /// ```
+    pub fn plot_line(src: Self, dest: Self) -> LineIter<T> {
+        LineIter::new(src, dest, false)
+    }
+
+    /// The same as plot_line, but dest is also included in the line.
+    ///
+    /// Lines are symmetric, so the points from src to dest are exactly the
+    /// points from dest to src in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let src = Point::new(1, 1);
+    /// let dest = Point::new(4, 0);
+    /// let line: Vec<Point> = Point::plot_line_inclusive(src, dest).collect();
+    ///
+    /// let expected: Vec<Point> = vec![(1, 1), (2, 1), (3, 0), (4, 0)].into_iter().map(Point::from).collect();
+    /// assert_eq!(line, expected);
+    /// assert_eq!(Point::plot_line_inclusive(src, dest).len(), 4);
+    /// assert_eq!(Point::plot_line_inclusive(src, dest).rev().next(), Some(dest));
+    /// ```
+    ///
+    /// Every line between points in a region is symmetric, sized correctly,
+    /// and connected:
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let points: Vec<Point> = (0..81).map(|i| Point::convert_up(i, 9) - Point::new(4, 4)).collect();
+    ///
+    /// for &a in &points {
+    ///     for &b in &points {
+    ///         let forward: Vec<Point> = Point::plot_line_inclusive(a, b).collect();
+    ///         let mut backward: Vec<Point> = Point::plot_line_inclusive(b, a).collect();
+    ///         backward.reverse();
+    ///
+    ///         assert_eq!(forward, backward);
+    ///         assert_eq!(Point::plot_line_inclusive(a, b).rev().collect::<Vec<_>>(), backward.iter().rev().copied().collect::<Vec<_>>());
+    ///         assert_eq!(forward.len(), Point::plot_line_inclusive(a, b).len());
+    ///         assert_eq!(forward.len() as i32, (a.x - b.x).abs().max((a.y - b.y).abs()) + 1);
+    ///         assert_eq!((forward[0], forward[forward.len() - 1]), (a, b));
+    ///         assert!(forward.windows(2).all(|w| w[0].adjacent_diagonal().contains(&w[1])));
+    ///         assert_eq!(Point::plot_line(a, b).collect::<Vec<_>>(), forward[..forward.len() - 1]);
+    ///     }
+    /// }
+    /// ```
+    pub fn plot_line_inclusive(src: Self, dest: Self) -> LineIter<T> {
+        LineIter::new(src, dest, true)
+    }
+}
+
+impl Point {
+    /// Returns a vector of points wrapped in options that would be orthogonally adjacent to the point.
+    /// Takes a maximum x and y co-ordinate, which the returned points will not
+    /// exceed or equal. They will also not be less than 0.
+    #[deprecated(since = "0.7.0", note = "Use adjacent_in_bounds instead.")]
+    pub fn get_adjacent(&self, max_x: i32, max_y: i32) -> Vec<Option<Self>> {
+        self.adjacent()
+            .map(|p| p.bounds_check(max_x, max_y).then_some(p))
+            .to_vec()
+    }
+
+    /// The same as get_adjacent, but the returned points are checked against
+    /// the given rect rather than a rect anchored at the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use point::{Point, Rect};
+    ///
+    /// let bounds = Rect::new(Point::new(2, 2), Point::new(5, 5));
+    /// let p = Point::new(2, 3);
+    ///
+    /// let expected = vec![Some(Point::new(3, 3)), Some(Point::new(2, 2)), None, Some(Point::new(2, 4))];
+    /// assert_eq!(p.get_adjacent_rect(&bounds), expected);
+    /// ```
+    #[deprecated(since = "0.7.0", note = "Use adjacent_in_rect instead.")]
+    pub fn get_adjacent_rect(&self, bounds: &Rect) -> Vec<Option<Self>> {
+        self.adjacent()
+            .map(|p| p.bounds_check_rect(bounds).then_some(p))
+            .to_vec()
+    }
+
+    /// Returns every point with a distance of 1 away from the point.
+    /// Does not include points with non integer co_ordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use point::Point;
+    ///
+    /// let mut p = Point::new(0, 0);
+    ///
+    /// let expected: Vec<Point> = vec![(1, 0), (0, -1), (-1, 0), (0, 1)].into_iter().map(Point::from).collect();
+    /// assert_eq!(p.get_all_adjacent(), expected);
+    /// ```
+    #[deprecated(since = "0.7.0", note = "Use adjacent instead.")]
+    pub fn get_all_adjacent(&self) -> Vec<Self> {
+        self.adjacent().to_vec()
+    }
+
+    /// The same as get_all_adjacent, but also returns diagonally adjacent
+    /// points.
+    #[deprecated(since = "0.7.0", note = "Use adjacent_diagonal instead.")]
+    pub fn get_all_adjacent_diagonal(&self) -> Vec<Self> {
+        self.adjacent_diagonal().to_vec()
+    }
 
     /// Maps the four unit points to 0, 1, 2 and 3 for indexing a
     /// collection with 4 elements.
     ///
     /// > Note: Use of this method outside of the unit points
-    /// > will return arbitrary and meaningless values.
+    /// > will return arbitrary and meaningless values. Prefer converting
+    /// > the point into a [Direction], which checks its input.
     ///
     /// # Examples
     ///
@@ -240,7 +775,8 @@ impl Point {
     /// the result of dir.
     ///
     /// > Note: Use of this method outside of the unit points
-    /// > will return arbitrary and meaningless values.
+    /// > will return arbitrary and meaningless values. Prefer converting
+    /// > the point into a [Direction], which checks its input.
     ///
     /// # Examples
     ///
@@ -263,62 +799,63 @@ impl Point {
         if dir < 2 { dir + 2 } else { dir - 2 }
     }
 
-    /// Returns the Euclidean distance between self and other,
-    /// using the pythagorean theorem.
+    /// Returns the squared Euclidean distance between self and other as a
+    /// u128, which cannot overflow for any pair of points.
+    ///
     /// # Examples
     ///
     /// ```
     /// use point::Point;
     ///
-    /// let p1 = Point::new(3, 0);
-    /// let p2 = Point::new(11, 6);
+    /// let p1 = Point::new(i32::MIN, i32::MIN);
+    /// let p2 = Point::new(i32::MAX, i32::MAX);
     ///
-    /// // 8^2 + 6^2 = 100
-    /// // sqrt(100) = 10
-    /// assert_eq!(p1.dist(p2), 10.0f64)
+    /// assert_eq!(p1.dist_squared_wide(p2), 2 * (u32::MAX as u128).pow(2));
+    /// assert_eq!(Point::new(0, 0).dist_squared_wide(Point::new(2, 1)), 5);
     /// ```
-    #[inline(always)]
-    pub fn dist(self, other: Self) -> f64 {
-        (self.dist_squared(other) as f64).sqrt()
+    #[inline]
+    pub const fn dist_squared_wide(self, other: Self) -> u128 {
+        let dx = self.x.abs_diff(other.x) as u128;
+        let dy = self.y.abs_diff(other.y) as u128;
+        dx * dx + dy * dy
     }
 
-    /// Returns the squared Euclidean distance between self and other.
-    /// This is more performant than dist as the square root
-    /// step is skipped.
+    /// Returns the manhattan distance between self and other as a u64,
+    /// which cannot overflow for any pair of points.
     ///
     /// # Examples
     ///
     /// ```
     /// use point::Point;
     ///
-    /// let p1 = Point::new(0, 0);
-    /// let p2 = Point::new(2, 1);
+    /// let p1 = Point::new(i32::MIN, 0);
+    /// let p2 = Point::new(i32::MAX, -1);
     ///
-    /// // 2^2 + 1^2 = 5
-    /// assert_eq!(p1.dist_squared(p2), 5);
+    /// assert_eq!(p1.manhattan_dist_wide(p2), u32::MAX as u64 + 1);
     /// ```
     #[inline]
-    pub fn dist_squared(self, other: Self) -> i32 {
-        let disp = self - other;
-        disp.x * disp.x + disp.y * disp.y
+    pub const fn manhattan_dist_wide(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) as u64 + self.y.abs_diff(other.y) as u64
     }
 
-    /// Returns the manhattan distance between self and other.
+    /// Returns the Chebyshev distance between self and other as a u32,
+    /// which cannot overflow for any pair of points.
     ///
     /// # Examples
     ///
     /// ```
     /// use point::Point;
     ///
-    /// let p1 = Point::new(0, 0);
-    /// let p2 = Point::new(2, 1);
+    /// let p1 = Point::new(i32::MIN, 0);
+    /// let p2 = Point::new(i32::MAX, 5);
     ///
-    /// // 2-0 = 2, 1-0 = 1, 1+2=3
-    /// assert_eq!(p1.manhattan_dist(p2), 3);
+    /// assert_eq!(p1.chebyshev_dist_wide(p2), u32::MAX);
     /// ```
     #[inline]
-    pub fn manhattan_dist(self, other: Self) -> i32 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
+    pub const fn chebyshev_dist_wide(self, other: Self) -> u32 {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        if dx > dy { dx } else { dy }
     }
 
     /// Helper for converting an index into a two dimensional co-ordinate
@@ -404,101 +941,210 @@ impl Point {
         0 <= self.x && self.x < max_x && 0 <= self.y && self.y < max_y
     }
 
-    /// Returns an iterator over all the points on the line between src and dest.
-    /// Uses [Bresenham's Line Algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm) to do so.
-    /// Note that src is included in the line, but dest is not.
-    pub fn plot_line(src: Self, dest: Self) -> LineIter {
-        LineIter::new(src, dest)
+    /// Returns true if the point lies within the given rect. Equivalent to
+    /// [Rect::contains].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let bounds = Rect::new(Point::new(-5, -5), Point::new(5, 5));
+    ///
+    /// assert!(Point::new(-4, 4).bounds_check_rect(&bounds));
+    /// assert!(!Point::new(-4, 5).bounds_check_rect(&bounds));
+    /// ```
+    pub const fn bounds_check_rect(&self, bounds: &Rect) -> bool {
+        bounds.contains(*self)
     }
 
-    /// Returns self ⋅ other; that is, the [dot product](https://en.wikipedia.org/wiki/Dot_product), interpreting
-    /// self and other as 2D vectors.
+    /// Returns the sum of self and other, or None if either co-ordinate
+    /// overflows.
     ///
     /// # Examples
     ///
     /// ```
     /// use point::Point;
     ///
-    /// let p1 = Point::new(2, 3);
-    /// let p2 = Point::new(-4, 1);
+    /// let p = Point::new(i32::MAX - 1, 0);
     ///
-    /// // 2 * -4 + 3 * 1 = -5
-    /// assert_eq!(p1.dot(p2), -5);
-    pub fn dot(self, other: Self) -> i32 {
-        self.x * other.x + self.y * other.y
+    /// assert_eq!(p.checked_add(Point::new(1, 1)), Some(Point::new(i32::MAX, 1)));
+    /// assert_eq!(p.checked_add(Point::new(2, 0)), None);
+    /// ```
+    #[inline]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match (self.x.checked_add(other.x), self.y.checked_add(other.y)) {
+            (Some(x), Some(y)) => Some(Self { x, y }),
+            _ => None,
+        }
     }
-}
-
-/// An iterator over the points on a line between two points.
-pub struct LineIter {
-    dx: i32,
-    sx: i32,
-    dy: i32,
-    sy: i32,
-    err: i32,
-    cur_x: i32,
-    cur_y: i32,
-    end_x: i32,
-    end_y: i32,
-}
 
-impl LineIter {
-    fn new(src: Point, dest: Point) -> Self {
-        let cur_x = src.x;
-        let end_x = dest.x;
-
-        let cur_y = src.y;
-        let end_y = dest.y;
-
-        let dx = (end_x - cur_x).abs();
-        let sx = if cur_x < end_x { 1 } else { -1 };
-
-        let dy = -(end_y - cur_y).abs();
-        let sy = if cur_y < end_y { 1 } else { -1 };
+    /// Returns the difference of self and other, or None if either
+    /// co-ordinate overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(0, i32::MIN);
+    ///
+    /// assert_eq!(p.checked_sub(Point::new(1, -1)), Some(Point::new(-1, i32::MIN + 1)));
+    /// assert_eq!(p.checked_sub(Point::new(0, 1)), None);
+    /// ```
+    #[inline]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match (self.x.checked_sub(other.x), self.y.checked_sub(other.y)) {
+            (Some(x), Some(y)) => Some(Self { x, y }),
+            _ => None,
+        }
+    }
 
-        let err = dx + dy;
+    /// Returns self with each co-ordinate multiplied by the given multiplier,
+    /// or None if either co-ordinate overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(i32::MIN, 1);
+    ///
+    /// assert_eq!(p.checked_mul(1), Some(p));
+    /// assert_eq!(p.checked_mul(-1), None);
+    /// ```
+    #[inline]
+    pub const fn checked_mul(self, other: i32) -> Option<Self> {
+        match (self.x.checked_mul(other), self.y.checked_mul(other)) {
+            (Some(x), Some(y)) => Some(Self { x, y }),
+            _ => None,
+        }
+    }
 
+    /// Returns the sum of self and other, wrapping around at the boundaries
+    /// of i32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(i32::MAX, 0);
+    ///
+    /// assert_eq!(p.wrapping_add(Point::new(1, 1)), Point::new(i32::MIN, 1));
+    /// ```
+    #[inline]
+    pub const fn wrapping_add(self, other: Self) -> Self {
         Self {
-            dx,
-            sx,
-            dy,
-            sy,
-            err,
-            cur_x,
-            cur_y,
-            end_x,
-            end_y,
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
         }
     }
-}
 
-impl Iterator for LineIter {
-    type Item = Point;
+    /// Returns the difference of self and other, wrapping around at the
+    /// boundaries of i32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(i32::MIN, 0);
+    ///
+    /// assert_eq!(p.wrapping_sub(Point::new(1, 1)), Point::new(i32::MAX, -1));
+    /// ```
+    #[inline]
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let nx = Point::new(self.cur_x, self.cur_y);
-        let e2 = 2 * self.err;
+    /// Returns self with each co-ordinate multiplied by the given multiplier,
+    /// wrapping around at the boundaries of i32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(i32::MIN, 3);
+    ///
+    /// assert_eq!(p.wrapping_mul(-1), Point::new(i32::MIN, -3));
+    /// ```
+    #[inline]
+    pub const fn wrapping_mul(self, other: i32) -> Self {
+        Self {
+            x: self.x.wrapping_mul(other),
+            y: self.y.wrapping_mul(other),
+        }
+    }
 
-        if e2 >= self.dy {
-            if self.cur_x == self.end_x {
-                return None;
-            }
-            self.err += self.dy;
-            self.cur_x += self.sx;
+    /// Returns the sum of self and other, clamping each co-ordinate to the
+    /// boundaries of i32 instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(i32::MAX - 1, i32::MIN + 1);
+    ///
+    /// assert_eq!(p.saturating_add(Point::new(5, -5)), Point::new(i32::MAX, i32::MIN));
+    /// ```
+    #[inline]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
         }
-        if e2 <= self.dx {
-            if self.cur_y == self.end_y {
-                return None;
-            }
-            self.err += self.dx;
-            self.cur_y += self.sy;
+    }
+
+    /// Returns the difference of self and other, clamping each co-ordinate to
+    /// the boundaries of i32 instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(i32::MIN, 0);
+    ///
+    /// assert_eq!(p.saturating_sub(Point::new(1, i32::MIN)), Point::new(i32::MIN, i32::MAX));
+    /// ```
+    #[inline]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
         }
+    }
 
-        Some(nx)
+    /// Returns self with each co-ordinate multiplied by the given multiplier,
+    /// clamping each co-ordinate to the boundaries of i32 instead of
+    /// overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(i32::MIN, 1 << 20);
+    ///
+    /// assert_eq!(p.saturating_mul(-1), Point::new(i32::MAX, -(1 << 20)));
+    /// assert_eq!(p.saturating_mul(1 << 12), Point::new(i32::MIN, i32::MAX));
+    /// ```
+    #[inline]
+    pub const fn saturating_mul(self, other: i32) -> Self {
+        Self {
+            x: self.x.saturating_mul(other),
+            y: self.y.saturating_mul(other),
+        }
     }
 }
 
-impl ops::Add for Point {
+impl<T: Scalar> ops::Add for Point<T> {
     type Output = Self;
 
     /// Returns a new point containing the sum of the points' x and y values.
@@ -510,7 +1156,7 @@ impl ops::Add for Point {
     }
 }
 
-impl ops::Sub for Point {
+impl<T: Scalar> ops::Sub for Point<T> {
     type Output = Self;
 
     /// Returns a new point containing the difference of the points' x and y values.
@@ -522,7 +1168,7 @@ impl ops::Sub for Point {
     }
 }
 
-impl ops::Neg for Point {
+impl<T: Signed> ops::Neg for Point<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
@@ -533,11 +1179,11 @@ impl ops::Neg for Point {
     }
 }
 
-impl ops::Div<i32> for Point {
+impl<T: Scalar> ops::Div<T> for Point<T> {
     type Output = Self;
 
     /// Returns a new point containg each co-ordinate divided by the given divisor.
-    fn div(self, other: i32) -> Self::Output {
+    fn div(self, other: T) -> Self::Output {
         Self {
             x: self.x / other,
             y: self.y / other,
@@ -545,11 +1191,11 @@ impl ops::Div<i32> for Point {
     }
 }
 
-impl ops::Mul<i32> for Point {
+impl<T: Scalar> ops::Mul<T> for Point<T> {
     type Output = Self;
 
     /// Returns a new point containg each co-ordinate multiplied by the given multiplier.
-    fn mul(self, other: i32) -> Self::Output {
+    fn mul(self, other: T) -> Self::Output {
         Self {
             x: self.x * other,
             y: self.y * other,
@@ -557,17 +1203,43 @@ impl ops::Mul<i32> for Point {
     }
 }
 
-impl fmt::Display for Point {
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    /// Formats the point as `(x, y)`, or as `x,y` if the alternate flag is
+    /// specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    ///
+    /// let p = Point::new(3, -4);
+    ///
+    /// assert_eq!(format!("{p}"), "(3, -4)");
+    /// assert_eq!(format!("{p:#}"), "3,-4");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
+        if f.alternate() {
+            write!(f, "{},{}", self.x, self.y)
+        } else {
+            write!(f, "({}, {})", self.x, self.y)
+        }
     }
 }
 
-impl<T> From<(T, T)> for Point
+impl From<Point> for Vec2 {
+    fn from(val: Point) -> Self {
+        Self {
+            x: val.x.into(),
+            y: val.y.into(),
+        }
+    }
+}
+
+impl<T, S> From<(S, S)> for Point<T>
 where
-    T: Into<i32>,
+    S: Into<T>,
 {
-    fn from(val: (T, T)) -> Self {
+    fn from(val: (S, S)) -> Self {
         Self {
             x: val.0.into(),
             y: val.1.into(),
@@ -575,11 +1247,11 @@ where
     }
 }
 
-impl<U> From<Point> for (U, U)
+impl<T, U> From<Point<T>> for (U, U)
 where
-    i32: Into<U>,
+    T: Into<U>,
 {
-    fn from(val: Point) -> Self {
+    fn from(val: Point<T>) -> Self {
         (val.x.into(), val.y.into())
     }
 }
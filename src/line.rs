@@ -0,0 +1,441 @@
+//! Iterators over the points on a line.
+//!
+//! [LineIter] produces the thinnest possible line, which can slip diagonally
+//! between two points that the ideal line passes through. The other iterators
+//! in this module cover more of the grid where that matters.
+use crate::{Point, Vec2, scalar::Integer};
+use std::{iter::FusedIterator, marker::PhantomData};
+
+/// An iterator over the points on a line between two points.
+///
+/// The line steps one point at a time along its major axis, the axis in
+/// which src and dest differ the most, with the position on the minor axis
+/// rounded to the nearest integer. Lines are always plotted starting from
+/// the lesser of their two end points, so that ties in the rounding are
+/// broken the same way whichever direction the line is drawn in.
+///
+/// The [len](ExactSizeIterator::len) of a line saturates at usize::MAX, which
+/// only matters for lines of 64-bit or wider integers longer than that.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+///
+/// let line = Point::plot_line_inclusive(Point::new(i64::MIN, 0), Point::new(i64::MAX, 0));
+/// assert_eq!(line.len(), usize::MAX);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineIter<T = i32> {
+    start_x: i128,
+    start_y: i128,
+    major_is_x: bool,
+    major_step: i128,
+    minor_disp: i128,
+    len: i128,
+    reversed: bool,
+    front: i128,
+    back: i128,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Integer> LineIter<T> {
+    pub(crate) fn new(src: Point<T>, dest: Point<T>, inclusive: bool) -> Self {
+        let (sx, sy) = (src.x.to_i128(), src.y.to_i128());
+        let (dx, dy) = (dest.x.to_i128(), dest.y.to_i128());
+        let reversed = (dx, dy) < (sx, sy);
+        let (start_x, start_y, end_x, end_y) = if reversed {
+            (dx, dy, sx, sy)
+        } else {
+            (sx, sy, dx, dy)
+        };
+
+        let (disp_x, disp_y) = (end_x - start_x, end_y - start_y);
+        let major_is_x = disp_x.abs() >= disp_y.abs();
+        let (major_disp, minor_disp) = if major_is_x {
+            (disp_x, disp_y)
+        } else {
+            (disp_y, disp_x)
+        };
+        let len = major_disp.abs();
+
+        Self {
+            start_x,
+            start_y,
+            major_is_x,
+            major_step: major_disp.signum(),
+            minor_disp,
+            len,
+            reversed,
+            front: 0,
+            back: if inclusive { len + 1 } else { len },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the point at the given position, counting from src.
+    fn point_at(&self, pos: i128) -> Point<T> {
+        let i = if self.reversed { self.len - pos } else { pos };
+        let major = i * self.major_step;
+        // Rounds i * minor_disp / len to the nearest integer, rounding ties
+        // towards positive infinity.
+        let minor = if self.len == 0 {
+            0
+        } else {
+            (2 * i * self.minor_disp + self.len).div_euclid(2 * self.len)
+        };
+        let (x, y) = if self.major_is_x {
+            (major, minor)
+        } else {
+            (minor, major)
+        };
+
+        Point::new(
+            T::from_i128(self.start_x + x),
+            T::from_i128(self.start_y + y),
+        )
+    }
+}
+
+impl<T: Integer> Iterator for LineIter<T> {
+    type Item = Point<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let p = self.point_at(self.front);
+        self.front += 1;
+        Some(p)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front).max(0);
+        match usize::try_from(remaining) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, Some(usize::MAX)),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n as i128).min(self.back);
+        self.next()
+    }
+}
+
+impl<T: Integer> DoubleEndedIterator for LineIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.point_at(self.back))
+    }
+}
+
+impl<T: Integer> ExactSizeIterator for LineIter<T> {}
+
+impl<T: Integer> FusedIterator for LineIter<T> {}
+
+/// An iterator over every point whose cell is touched by the segment between
+/// the centres of two cells, treating each point as the centre of a unit
+/// square. Where the segment passes exactly through a corner, all of the cells
+/// sharing the corner are included.
+///
+/// Unlike [LineIter](crate::LineIter), both src and dest are included.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+///
+/// let line: Vec<Point> = Point::plot_supercover(Point::new(0, 0), Point::new(2, 1)).collect();
+///
+/// let expected: Vec<Point> = vec![(0, 0), (1, 0), (1, 1), (2, 1)].into_iter().map(Point::from).collect();
+/// assert_eq!(line, expected);
+///
+/// // A perfect diagonal touches the cells either side of each corner.
+/// let diagonal: Vec<Point> = Point::plot_supercover(Point::new(0, 0), Point::new(1, 1)).collect();
+///
+/// let expected: Vec<Point> = vec![(0, 0), (1, 0), (0, 1), (1, 1)].into_iter().map(Point::from).collect();
+/// assert_eq!(diagonal, expected);
+///
+/// // Lines may span the whole range of i32.
+/// let long: Vec<Point> = Point::plot_supercover(Point::new(i32::MAX, 0), Point::new(i32::MIN, 1)).take(2).collect();
+///
+/// assert_eq!(long, vec![Point::new(i32::MAX, 0), Point::new(i32::MAX - 1, 0)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SupercoverIter {
+    cur: Point,
+    sx: i32,
+    sy: i32,
+    nx: i128,
+    ny: i128,
+    ix: i128,
+    iy: i128,
+    started: bool,
+    /// Points still to be yielded after passing through a corner.
+    pending: [Option<Point>; 2],
+}
+
+impl SupercoverIter {
+    /// Returns an iterator over the cells touched by the segment from src to dest.
+    pub fn new(src: Point, dest: Point) -> Self {
+        Self {
+            cur: src,
+            sx: dest.x.cmp(&src.x) as i32,
+            sy: dest.y.cmp(&src.y) as i32,
+            nx: src.x.abs_diff(dest.x) as i128,
+            ny: src.y.abs_diff(dest.y) as i128,
+            ix: 0,
+            iy: 0,
+            started: false,
+            pending: [None, None],
+        }
+    }
+}
+
+impl Iterator for SupercoverIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(p) = self.pending[0].take() {
+            self.pending.swap(0, 1);
+            return Some(p);
+        }
+        if !self.started {
+            self.started = true;
+            return Some(self.cur);
+        }
+        if self.ix == self.nx && self.iy == self.ny {
+            return None;
+        }
+
+        // Compare the distances along the segment to the next vertical and
+        // horizontal cell boundaries, scaled to avoid division.
+        let to_x = (1 + 2 * self.ix) * self.ny;
+        let to_y = (1 + 2 * self.iy) * self.nx;
+
+        if to_x == to_y {
+            let side_x = Point::new(self.cur.x + self.sx, self.cur.y);
+            let side_y = Point::new(self.cur.x, self.cur.y + self.sy);
+            self.cur = Point::new(self.cur.x + self.sx, self.cur.y + self.sy);
+            self.ix += 1;
+            self.iy += 1;
+            self.pending = [Some(side_y), Some(self.cur)];
+            return Some(side_x);
+        } else if to_x < to_y {
+            self.cur.x += self.sx;
+            self.ix += 1;
+        } else {
+            self.cur.y += self.sy;
+            self.iy += 1;
+        }
+
+        Some(self.cur)
+    }
+}
+
+impl FusedIterator for SupercoverIter {}
+
+/// An iterator over the cells crossed by a segment between two fractional
+/// positions, using the
+/// [Amanatides–Woo](http://www.cse.yorku.ca/~amana/research/grid.pdf) grid
+/// traversal algorithm.
+///
+/// The cell represented by a point p covers every position whose co-ordinates
+/// round down to p, as with [Rounding::Floor](crate::Rounding::Floor). Cells
+/// are yielded in the order the segment enters them, starting with the cell
+/// containing src and ending with the cell containing dest.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, Vec2};
+///
+/// let cells: Vec<Point> = Vec2::traverse_grid(Vec2::new(0.5, 0.5), Vec2::new(2.5, 1.2)).collect();
+///
+/// let expected: Vec<Point> = vec![(0, 0), (1, 0), (1, 1), (2, 1)].into_iter().map(Point::from).collect();
+/// assert_eq!(cells, expected);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GridTraversal {
+    cur: Point,
+    end: Point,
+    step_x: i32,
+    step_y: i32,
+    t_max_x: f64,
+    t_max_y: f64,
+    t_delta_x: f64,
+    t_delta_y: f64,
+    done: bool,
+}
+
+impl GridTraversal {
+    /// Returns an iterator over the cells crossed by the segment from src to dest.
+    pub fn new(src: Vec2, dest: Vec2) -> Self {
+        let cur = Point::new(src.x.floor() as i32, src.y.floor() as i32);
+        let end = Point::new(dest.x.floor() as i32, dest.y.floor() as i32);
+        let (step_x, t_max_x, t_delta_x) = Self::axis(src.x, dest.x);
+        let (step_y, t_max_y, t_delta_y) = Self::axis(src.y, dest.y);
+
+        Self {
+            cur,
+            end,
+            step_x,
+            step_y,
+            t_max_x,
+            t_max_y,
+            t_delta_x,
+            t_delta_y,
+            done: false,
+        }
+    }
+
+    /// Returns the step direction along an axis, the fraction of the segment
+    /// travelled before crossing the first cell boundary on that axis, and
+    /// the fraction of the segment between successive boundaries.
+    fn axis(src: f64, dest: f64) -> (i32, f64, f64) {
+        let d = dest - src;
+        if d > 0.0 {
+            (1, (src.floor() + 1.0 - src) / d, 1.0 / d)
+        } else if d < 0.0 {
+            (-1, (src - src.floor()) / -d, 1.0 / -d)
+        } else {
+            (0, f64::INFINITY, f64::INFINITY)
+        }
+    }
+}
+
+impl Iterator for GridTraversal {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let cell = self.cur;
+
+        // Stopping once the next boundary lies beyond the end of the segment
+        // guards against rounding errors skipping over the final cell.
+        if cell == self.end || self.t_max_x.min(self.t_max_y) > 1.0 {
+            self.done = true;
+        } else if self.t_max_x < self.t_max_y {
+            self.t_max_x += self.t_delta_x;
+            self.cur.x += self.step_x;
+        } else {
+            self.t_max_y += self.t_delta_y;
+            self.cur.y += self.step_y;
+        }
+
+        Some(cell)
+    }
+}
+
+impl FusedIterator for GridTraversal {}
+
+/// An iterator over the points of a line drawn with a given thickness.
+///
+/// The line is traced with [Point::plot_line_inclusive], and each point on it is
+/// extended into a span of points across the line's minor axis. That is,
+/// mostly horizontal lines are thickened vertically, and mostly vertical
+/// lines are thickened horizontally. Both src and dest are included, and
+/// each point is yielded exactly once.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+///
+/// let line: Vec<Point> = Point::plot_thick_line(Point::new(0, 0), Point::new(2, 0), 3).collect();
+///
+/// let expected: Vec<Point> = vec![(0, -1), (0, 0), (0, 1), (1, -1), (1, 0), (1, 1), (2, -1), (2, 0), (2, 1)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// assert_eq!(line, expected);
+/// assert_eq!(Point::plot_thick_line(Point::new(0, 0), Point::new(3, 0), 0).count(), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThickLineIter {
+    line: LineIter,
+    across: Point,
+    lo: i32,
+    hi: i32,
+    centre: Option<Point>,
+    offset: i32,
+}
+
+impl ThickLineIter {
+    /// Returns an iterator over the points of the line from src to dest,
+    /// extended to the given thickness. A thickness of 0 yields no points.
+    pub fn new(src: Point, dest: Point, thickness: u32) -> Self {
+        let across = if src.x.abs_diff(dest.x) >= src.y.abs_diff(dest.y) {
+            Point::new(0, 1)
+        } else {
+            Point::new(1, 0)
+        };
+        let thickness = thickness.min(i32::MAX as u32) as i32;
+        // An empty range of offsets, so that nothing is yielded.
+        let (lo, hi) = if thickness == 0 {
+            (1, 0)
+        } else {
+            (-(thickness - 1) / 2, thickness / 2)
+        };
+
+        Self {
+            line: Point::plot_line_inclusive(src, dest),
+            across,
+            lo,
+            hi,
+            centre: None,
+            offset: 0,
+        }
+    }
+}
+
+impl Iterator for ThickLineIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.lo > self.hi {
+            return None;
+        }
+        loop {
+            if let Some(centre) = self.centre
+                && self.offset <= self.hi
+            {
+                let p = centre + self.across * self.offset;
+                self.offset += 1;
+                return Some(p);
+            }
+            self.centre = Some(self.line.next()?);
+            self.offset = self.lo;
+        }
+    }
+}
+
+impl FusedIterator for ThickLineIter {}
+
+impl Point {
+    /// Returns an iterator over every point whose cell is touched by the
+    /// segment between src and dest. See [SupercoverIter] for details.
+    pub fn plot_supercover(src: Self, dest: Self) -> SupercoverIter {
+        SupercoverIter::new(src, dest)
+    }
+
+    /// Returns an iterator over the points of the line between src and dest,
+    /// drawn with the given thickness. See [ThickLineIter] for details.
+    pub fn plot_thick_line(src: Self, dest: Self, thickness: u32) -> ThickLineIter {
+        ThickLineIter::new(src, dest, thickness)
+    }
+}
+
+impl Vec2 {
+    /// Returns an iterator over the cells crossed by the segment between src
+    /// and dest. See [GridTraversal] for details.
+    pub fn traverse_grid(src: Self, dest: Self) -> GridTraversal {
+        GridTraversal::new(src, dest)
+    }
+}
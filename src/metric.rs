@@ -0,0 +1,115 @@
+//! Measures of the distance between two [Point]s.
+//!
+//! Each metric is a unit struct implementing [Metric], so that searches, range
+//! queries and shapes can be parameterised by the distance they use. For
+//! example, passing [Chebyshev] to [DiskIter::with_metric](crate::DiskIter::with_metric)
+//! gives a square, and passing [Manhattan] gives a diamond.
+use crate::Point;
+
+/// A measure of the distance between two points.
+///
+/// The distance must only depend on the absolute differences between the x
+/// and y co-ordinates of the points, must never decrease as either difference
+/// grows, and must be at least the larger of the two differences. Nearest
+/// neighbour searches and shapes rely on this to avoid checking every point.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::metric::{Chebyshev, Euclidean, Manhattan, Metric, Octile};
+///
+/// let (p1, p2) = (Point::new(0, 0), Point::new(3, 4));
+///
+/// assert_eq!(Euclidean.dist(p1, p2), 5.0);
+/// assert_eq!(Manhattan.dist(p1, p2), 7.0);
+/// assert_eq!(Chebyshev.dist(p1, p2), 4.0);
+/// assert_eq!(Octile.dist(p1, p2), 1.0 + 3.0 * std::f64::consts::SQRT_2);
+/// ```
+pub trait Metric {
+    /// Returns the distance between a and b.
+    fn dist(&self, a: Point, b: Point) -> f64;
+
+    /// Returns true if a and b are no more than radius apart. The default
+    /// implementation compares the result of [Metric::dist] with radius.
+    #[inline]
+    fn within(&self, a: Point, b: Point, radius: u32) -> bool {
+        self.dist(a, b) <= radius as f64
+    }
+}
+
+/// The straight line distance, as given by [Point::dist].
+///
+/// Radius checks are exact, using [Point::dist_squared_wide].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Euclidean;
+
+impl Metric for Euclidean {
+    #[inline]
+    fn dist(&self, a: Point, b: Point) -> f64 {
+        a.dist(b)
+    }
+
+    #[inline]
+    fn within(&self, a: Point, b: Point, radius: u32) -> bool {
+        a.dist_squared_wide(b) <= radius as u128 * radius as u128
+    }
+}
+
+/// The number of orthogonal steps between two points, as given by
+/// [Point::manhattan_dist_wide].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Manhattan;
+
+impl Metric for Manhattan {
+    #[inline]
+    fn dist(&self, a: Point, b: Point) -> f64 {
+        a.manhattan_dist_wide(b) as f64
+    }
+
+    #[inline]
+    fn within(&self, a: Point, b: Point, radius: u32) -> bool {
+        a.manhattan_dist_wide(b) <= radius as u64
+    }
+}
+
+/// The number of orthogonal or diagonal steps between two points, as given by
+/// [Point::chebyshev_dist_wide].
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Chebyshev;
+
+impl Metric for Chebyshev {
+    #[inline]
+    fn dist(&self, a: Point, b: Point) -> f64 {
+        a.chebyshev_dist_wide(b) as f64
+    }
+
+    #[inline]
+    fn within(&self, a: Point, b: Point, radius: u32) -> bool {
+        a.chebyshev_dist_wide(b) <= radius
+    }
+}
+
+/// The length of the shortest path between two points made of orthogonal
+/// steps of length 1 and diagonal steps of length √2, as given by
+/// [Point::octile_dist].
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::metric::{Metric, Octile};
+///
+/// let (a, b) = (Point::new(i32::MIN, 0), Point::new(i32::MAX, 1));
+///
+/// assert_eq!(Octile.dist(a, b), a.octile_dist(b));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Octile;
+
+impl Metric for Octile {
+    #[inline]
+    fn dist(&self, a: Point, b: Point) -> f64 {
+        a.octile_dist(b)
+    }
+}
@@ -0,0 +1,391 @@
+//! Seeded coherent noise, for generating terrain and other smoothly varying
+//! values over the plane.
+//!
+//! Each kind of noise implements [Noise], which samples it at fractional
+//! positions, at [Point]s, and over whole [Grid]s. Noise is made of random
+//! values or gradients at the integer lattice points, smoothly interpolated
+//! between them, so [Noise::at] takes a scale giving the number of points
+//! between lattice points.
+//!
+//! For toroidal maps, [ValueNoise] and [Perlin] noise can be made periodic, so
+//! that they wrap around seamlessly, as can an [Fbm] built from them. [Simplex]
+//! noise cannot tile, as its lattice of triangles is not aligned with the x
+//! and y axes.
+//!
+//! The noise only depends on its seed and the position sampled; it is
+//! computed using integer hashing and basic floating point arithmetic, so it
+//! is the same on every platform.
+use crate::{Grid, Point, Rng, Vec2};
+use std::f64::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+/// A function giving a value between -1 and 1 for every position on the
+/// plane, which varies smoothly between nearby positions.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::noise::{Fbm, Noise, Perlin, Simplex, ValueNoise};
+///
+/// let sources: [&dyn Noise; 4] = [&ValueNoise::new(1), &Perlin::new(1), &Simplex::new(1), &Fbm::new(Perlin::new(1), 4)];
+///
+/// for noise in sources {
+///     let heights = noise.fill(32, 32, 8.0);
+///
+///     assert!(heights.iter().all(|h| (-1.0..=1.0).contains(h)));
+///     assert_eq!(heights[Point::new(5, 9)], noise.at(Point::new(5, 9), 8.0));
+/// }
+/// ```
+pub trait Noise {
+    /// Returns the value of the noise at pos.
+    fn sample(&self, pos: Vec2) -> f64;
+
+    /// Returns the value of the noise at p, where scale is the distance
+    /// between lattice points. Larger scales give smoother noise.
+    #[inline]
+    fn at(&self, p: Point, scale: f64) -> f64 {
+        self.sample(Vec2::from(p) / scale)
+    }
+
+    /// Returns a grid of the given size, where each cell is the value of the
+    /// noise at its position, as given by [Noise::at].
+    fn fill(&self, width: usize, height: usize, scale: f64) -> Grid<f64> {
+        Grid::from_fn(width, height, |p| self.at(p, scale))
+    }
+}
+
+/// Returns a random u64 for the lattice point (x, y).
+#[inline]
+fn hash(seed: u64, x: i32, y: i32) -> u64 {
+    let key = ((x as u32 as u64) << 32) | y as u32 as u64;
+    Rng::new(Rng::new(seed).next_u64() ^ key).next_u64()
+}
+
+/// Returns the lattice point at (x, y), wrapped by the period if there is one.
+#[inline]
+fn wrap(x: i32, y: i32, period: Option<(i32, i32)>) -> (i32, i32) {
+    match period {
+        Some((px, py)) => (x.rem_euclid(px), y.rem_euclid(py)),
+        None => (x, y),
+    }
+}
+
+/// Returns the period in lattice points, checking that it is positive.
+fn checked_period(x: u32, y: u32) -> (i32, i32) {
+    assert!(
+        x > 0 && y > 0 && x <= i32::MAX as u32 && y <= i32::MAX as u32,
+        "noise period must be positive"
+    );
+    (x as i32, y as i32)
+}
+
+/// Returns 6t^5 - 15t^4 + 10t^3, which eases t between 0 and 1 so that the
+/// interpolated noise has no visible creases at lattice lines.
+#[inline]
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+#[inline]
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Splits pos into the lattice point below and left of it, and its offset
+/// from that point.
+#[inline]
+fn split(pos: Vec2) -> (i32, i32, f64, f64) {
+    let (x0, y0) = (pos.x.floor(), pos.y.floor());
+    (x0 as i32, y0 as i32, pos.x - x0, pos.y - y0)
+}
+
+/// Noise made by smoothly interpolating random values at the lattice points.
+/// It is blockier than [Perlin] noise, but is not zero at the lattice points.
+///
+/// # Examples
+///
+/// ```
+/// use point::Vec2;
+/// use point::noise::{Noise, ValueNoise};
+///
+/// let noise = ValueNoise::new(7);
+///
+/// assert_eq!(noise.sample(Vec2::new(2.5, 3.25)), ValueNoise::new(7).sample(Vec2::new(2.5, 3.25)));
+/// assert_ne!(noise.sample(Vec2::new(2.5, 3.25)), ValueNoise::new(8).sample(Vec2::new(2.5, 3.25)));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValueNoise {
+    seed: u64,
+    period: Option<(i32, i32)>,
+}
+
+impl ValueNoise {
+    /// Returns value noise with the given seed.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed, period: None }
+    }
+
+    /// Returns the same noise made to repeat every x lattice points
+    /// horizontally and every y lattice points vertically, so that it can be
+    /// used on a toroidal map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if x or y is zero or greater than i32::MAX.
+    pub fn periodic(self, x: u32, y: u32) -> Self {
+        Self {
+            period: Some(checked_period(x, y)),
+            ..self
+        }
+    }
+
+    /// Returns the random value at a lattice point, between -1 and 1.
+    #[inline]
+    fn value(&self, x: i32, y: i32) -> f64 {
+        let (x, y) = wrap(x, y, self.period);
+        (hash(self.seed, x, y) >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+    }
+}
+
+impl Noise for ValueNoise {
+    fn sample(&self, pos: Vec2) -> f64 {
+        let (x, y, fx, fy) = split(pos);
+        let (x1, y1) = (x.wrapping_add(1), y.wrapping_add(1));
+        let (u, v) = (fade(fx), fade(fy));
+
+        lerp(
+            lerp(self.value(x, y), self.value(x1, y), u),
+            lerp(self.value(x, y1), self.value(x1, y1), u),
+            v,
+        )
+    }
+}
+
+/// [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise), made by
+/// smoothly interpolating random gradients at the lattice points. The noise
+/// is always zero at the lattice points themselves.
+///
+/// # Examples
+///
+/// A map 64 points wide, sampled with a scale of 16, spans 4 lattice points,
+/// so noise with a period of 4 wraps around seamlessly.
+///
+/// ```
+/// use point::Point;
+/// use point::noise::{Noise, Perlin};
+///
+/// let noise = Perlin::new(3).periodic(4, 4);
+///
+/// for y in 0..64 {
+///     assert_eq!(noise.at(Point::new(0, y), 16.0), noise.at(Point::new(64, y), 16.0));
+///     assert_eq!(noise.at(Point::new(y, -1), 16.0), noise.at(Point::new(y, 63), 16.0));
+/// }
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Perlin {
+    seed: u64,
+    period: Option<(i32, i32)>,
+}
+
+/// The gradients at the lattice points of Perlin noise; the eight unit
+/// vectors pointing orthogonally and diagonally.
+const UNIT_GRADIENTS: [(f64, f64); 8] = [
+    (1.0, 0.0),
+    (-1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, -1.0),
+    (FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+    (-FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+    (FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+    (-FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+];
+
+impl Perlin {
+    /// Returns Perlin noise with the given seed.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed, period: None }
+    }
+
+    /// Returns the same noise made to repeat every x lattice points
+    /// horizontally and every y lattice points vertically, so that it can be
+    /// used on a toroidal map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if x or y is zero or greater than i32::MAX.
+    pub fn periodic(self, x: u32, y: u32) -> Self {
+        Self {
+            period: Some(checked_period(x, y)),
+            ..self
+        }
+    }
+
+    /// Returns the dot product of the gradient at the lattice point (x, y)
+    /// with the offset (dx, dy) from it.
+    #[inline]
+    fn influence(&self, x: i32, y: i32, dx: f64, dy: f64) -> f64 {
+        let (x, y) = wrap(x, y, self.period);
+        let (gx, gy) = UNIT_GRADIENTS[(hash(self.seed, x, y) >> 61) as usize];
+        gx * dx + gy * dy
+    }
+}
+
+impl Noise for Perlin {
+    fn sample(&self, pos: Vec2) -> f64 {
+        let (x, y, fx, fy) = split(pos);
+        let (x1, y1) = (x.wrapping_add(1), y.wrapping_add(1));
+        let (u, v) = (fade(fx), fade(fy));
+
+        let value = lerp(
+            lerp(
+                self.influence(x, y, fx, fy),
+                self.influence(x1, y, fx - 1.0, fy),
+                u,
+            ),
+            lerp(
+                self.influence(x, y1, fx, fy - 1.0),
+                self.influence(x1, y1, fx - 1.0, fy - 1.0),
+                u,
+            ),
+            v,
+        );
+        // With unit gradients, the magnitude never exceeds 1/√2.
+        (value * SQRT_2).clamp(-1.0, 1.0)
+    }
+}
+
+/// [Simplex noise](https://en.wikipedia.org/wiki/Simplex_noise), made from
+/// random gradients at the corners of a lattice of triangles. It has fewer
+/// directional artifacts than [Perlin] noise, but cannot be made periodic.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Simplex {
+    seed: u64,
+}
+
+/// (√3 - 1) / 2, which skews the plane so that the triangles become squares.
+const SKEW: f64 = 0.366_025_403_784_438_6;
+/// (3 - √3) / 6, which undoes [SKEW].
+const UNSKEW: f64 = 0.211_324_865_405_187_1;
+
+/// The gradients at the corners of the triangles of simplex noise.
+const SIMPLEX_GRADIENTS: [(f64, f64); 8] = [
+    (1.0, 1.0),
+    (-1.0, 1.0),
+    (1.0, -1.0),
+    (-1.0, -1.0),
+    (1.0, 0.0),
+    (-1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, -1.0),
+];
+
+impl Simplex {
+    /// Returns simplex noise with the given seed.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the contribution of the corner of a triangle at the skewed
+    /// lattice point (x, y), at the offset (dx, dy) from it.
+    #[inline]
+    fn corner(&self, x: i32, y: i32, dx: f64, dy: f64) -> f64 {
+        let t = 0.5 - dx * dx - dy * dy;
+        if t <= 0.0 {
+            return 0.0;
+        }
+        let (gx, gy) = SIMPLEX_GRADIENTS[(hash(self.seed, x, y) >> 61) as usize];
+        let t2 = t * t;
+        t2 * t2 * (gx * dx + gy * dy)
+    }
+}
+
+impl Noise for Simplex {
+    fn sample(&self, pos: Vec2) -> f64 {
+        let skew = (pos.x + pos.y) * SKEW;
+        let (i, j) = ((pos.x + skew).floor(), (pos.y + skew).floor());
+        let unskew = (i + j) * UNSKEW;
+        let (x0, y0) = (pos.x - (i - unskew), pos.y - (j - unskew));
+        let (i, j) = (i as i32, j as i32);
+
+        // The lower triangle of the skewed square is the one below the
+        // diagonal, where x0 > y0.
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let (x1, y1) = (x0 - i1 as f64 + UNSKEW, y0 - j1 as f64 + UNSKEW);
+        let (x2, y2) = (x0 - 1.0 + 2.0 * UNSKEW, y0 - 1.0 + 2.0 * UNSKEW);
+
+        let value = self.corner(i, j, x0, y0)
+            + self.corner(i.wrapping_add(i1), j.wrapping_add(j1), x1, y1)
+            + self.corner(i.wrapping_add(1), j.wrapping_add(1), x2, y2);
+        (70.0 * value).clamp(-1.0, 1.0)
+    }
+}
+
+/// [Fractal Brownian motion](https://en.wikipedia.org/wiki/Fractional_Brownian_motion);
+/// the sum of several octaves of another noise, each with a higher frequency
+/// and lower amplitude than the last, giving detail at many scales.
+///
+/// The octaves are offset from one another by whole lattice points, so if
+/// the noise is periodic and the lacunarity is a whole number, the sum has the
+/// same period.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::noise::{Fbm, Noise, ValueNoise};
+///
+/// let terrain = Fbm::new(ValueNoise::new(9).periodic(2, 2), 5);
+/// let map = terrain.fill(32, 32, 16.0);
+///
+/// assert_eq!(map[Point::new(0, 7)], terrain.at(Point::new(32, 7), 16.0));
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fbm<N> {
+    /// The noise summed in each octave.
+    pub noise: N,
+    /// The number of octaves.
+    pub octaves: u32,
+    /// The factor by which the frequency increases with each octave.
+    pub lacunarity: f64,
+    /// The factor by which the amplitude decreases with each octave.
+    pub gain: f64,
+}
+
+impl<N: Noise> Fbm<N> {
+    /// Returns the sum of the given number of octaves of noise, with a
+    /// lacunarity of 2 and a gain of 0.5.
+    #[inline]
+    pub const fn new(noise: N, octaves: u32) -> Self {
+        Self {
+            noise,
+            octaves,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+}
+
+impl<N: Noise> Noise for Fbm<N> {
+    fn sample(&self, pos: Vec2) -> f64 {
+        let (mut total, mut norm) = (0.0, 0.0);
+        let (mut frequency, mut amplitude) = (1.0, 1.0);
+
+        for octave in 0..self.octaves {
+            // Without an offset, every octave would be zero at the origin.
+            let offset = Vec2::new(octave as f64 * 131.0, octave as f64 * 197.0);
+            total += amplitude * self.noise.sample(pos * frequency + offset);
+            norm += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.gain;
+        }
+
+        if norm > 0.0 { total / norm } else { 0.0 }
+    }
+}
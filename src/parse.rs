@@ -0,0 +1,98 @@
+//! Parsing points from strings.
+use crate::Point;
+use std::{
+    error, fmt,
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+/// The error returned when parsing a [Point] from a string fails.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum ParsePointError {
+    /// The string did not contain both an x and a y co-ordinate.
+    MissingComponent,
+    /// The string contained more than two co-ordinates, or had unbalanced
+    /// parentheses.
+    InvalidFormat,
+    /// A co-ordinate was not a valid integer.
+    InvalidInteger,
+    /// A co-ordinate was an integer too large or too small to be represented.
+    Overflow,
+}
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingComponent => "missing co-ordinate in point",
+            Self::InvalidFormat => "invalid point syntax",
+            Self::InvalidInteger => "invalid integer in point",
+            Self::Overflow => "co-ordinate out of range in point",
+        })
+    }
+}
+
+impl error::Error for ParsePointError {}
+
+impl From<ParseIntError> for ParsePointError {
+    fn from(err: ParseIntError) -> Self {
+        match err.kind() {
+            IntErrorKind::Empty => Self::MissingComponent,
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => Self::Overflow,
+            _ => Self::InvalidInteger,
+        }
+    }
+}
+
+impl<T> FromStr for Point<T>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    type Err = ParsePointError;
+
+    /// Parses a point from the form emitted by its [Display](fmt::Display)
+    /// implementation, `(x, y)`, as well as `x,y` and `x y`. Whitespace
+    /// around the parentheses and co-ordinates is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{ParsePointError, Point};
+    ///
+    /// let p = Point::new(3, -4);
+    ///
+    /// assert_eq!(p.to_string().parse(), Ok(p));
+    /// assert_eq!(" ( 3 ,-4 ) ".parse(), Ok(p));
+    /// assert_eq!("3,-4".parse(), Ok(p));
+    /// assert_eq!("3   -4".parse(), Ok(p));
+    ///
+    /// assert_eq!("(3, )".parse::<Point>(), Err(ParsePointError::MissingComponent));
+    /// assert_eq!("(3, 4, 5)".parse::<Point>(), Err(ParsePointError::InvalidFormat));
+    /// assert_eq!("(3, 4".parse::<Point>(), Err(ParsePointError::InvalidFormat));
+    /// assert_eq!("(3.5, 4)".parse::<Point>(), Err(ParsePointError::InvalidInteger));
+    /// assert_eq!("(300, 4)".parse::<Point<u8>>(), Err(ParsePointError::Overflow));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let inner = match (s.strip_prefix('('), s.ends_with(')')) {
+            (Some(rest), true) => &rest[..rest.len() - 1],
+            (None, false) => s,
+            _ => return Err(ParsePointError::InvalidFormat),
+        };
+
+        let mut parts: Vec<&str> = if inner.contains(',') {
+            inner.split(',').map(str::trim).collect()
+        } else {
+            inner.split_whitespace().collect()
+        };
+
+        match parts.len() {
+            0 | 1 => Err(ParsePointError::MissingComponent),
+            2 => {
+                let y = parts.pop().unwrap().parse()?;
+                let x = parts.pop().unwrap().parse()?;
+                Ok(Self { x, y })
+            }
+            _ => Err(ParsePointError::InvalidFormat),
+        }
+    }
+}
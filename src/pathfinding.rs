@@ -0,0 +1,363 @@
+//! Shortest path searches over graphs of [Point]s.
+//!
+//! The graph is implicit: the neighbours of a point are given by a
+//! [Neighbourhood], and the cost of moving between two neighbours is given by
+//! a closure. Returning None from the closure marks a move as impassable.
+//!
+//! As the plane is unbounded, the closure is also responsible for limiting the
+//! search area, typically by returning None for any point outside of the map.
+//! Otherwise, searching for an unreachable goal will never terminate.
+use crate::{Point, metric::Metric};
+use std::{
+    array,
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    iter::FusedIterator,
+};
+
+/// The set of points considered adjacent to a point during a search.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighbourhood {
+    /// The four orthogonally adjacent points, as given by [Point::adjacent].
+    Four,
+    /// The eight orthogonally and diagonally adjacent points, as given by
+    /// [Point::adjacent_diagonal].
+    Eight,
+}
+
+impl Neighbourhood {
+    /// Returns an iterator over the points adjacent to pos in this
+    /// neighbourhood, without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::pathfinding::Neighbourhood;
+    ///
+    /// let p = Point::new(2, 5);
+    ///
+    /// assert!(Neighbourhood::Four.neighbours(p).eq(p.adjacent()));
+    /// assert_eq!(Neighbourhood::Eight.neighbours(p).len(), 8);
+    /// ```
+    pub fn neighbours(self, pos: Point) -> Neighbours {
+        Neighbours(match self {
+            Self::Four => NeighboursInner::Four(pos.adjacent().into_iter()),
+            Self::Eight => NeighboursInner::Eight(pos.adjacent_diagonal().into_iter()),
+        })
+    }
+}
+
+/// An iterator over the points adjacent to a point in a [Neighbourhood].
+/// Created by [Neighbourhood::neighbours].
+#[derive(Clone, Debug)]
+pub struct Neighbours(NeighboursInner);
+
+#[derive(Clone, Debug)]
+enum NeighboursInner {
+    Four(array::IntoIter<Point, 4>),
+    Eight(array::IntoIter<Point, 8>),
+}
+
+impl Iterator for Neighbours {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            NeighboursInner::Four(iter) => iter.next(),
+            NeighboursInner::Eight(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Neighbours {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            NeighboursInner::Four(iter) => iter.next_back(),
+            NeighboursInner::Eight(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl ExactSizeIterator for Neighbours {
+    #[inline]
+    fn len(&self) -> usize {
+        match &self.0 {
+            NeighboursInner::Four(iter) => iter.len(),
+            NeighboursInner::Eight(iter) => iter.len(),
+        }
+    }
+}
+
+impl FusedIterator for Neighbours {}
+
+/// A path found by a search, along with the total cost of following it.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Path {
+    /// Every point on the path, including the start and the goal.
+    pub points: Vec<Point>,
+    /// The sum of the costs of every move along the path.
+    pub cost: u32,
+}
+
+/// An entry in the open set of a search, ordered so that the entry with the
+/// lowest estimated total cost is popped from a [BinaryHeap] first.
+#[derive(PartialEq, Eq)]
+struct Node {
+    estimate: u32,
+    cost: u32,
+    pos: Point,
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            // Prefer nodes further along their path when estimates are tied.
+            .then_with(|| self.cost.cmp(&other.cost))
+            .then_with(|| (other.pos.x, other.pos.y).cmp(&(self.pos.x, self.pos.y)))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Follows the links in came_from back from goal, returning the points in
+/// order from the start to goal.
+fn reconstruct(came_from: &HashMap<Point, Point>, goal: Point) -> Vec<Point> {
+    let mut points = vec![goal];
+    let mut cur = goal;
+    while let Some(&prev) = came_from.get(&cur) {
+        points.push(prev);
+        cur = prev;
+    }
+    points.reverse();
+    points
+}
+
+/// Best first search shared by [astar] and [dijkstra].
+fn search<C, H>(
+    start: Point,
+    goal: Point,
+    neighbourhood: Neighbourhood,
+    mut cost: C,
+    heuristic: H,
+) -> Option<Path>
+where
+    C: FnMut(Point, Point) -> Option<u32>,
+    H: Fn(Point) -> u32,
+{
+    let mut open = BinaryHeap::new();
+    let mut best: HashMap<Point, u32> = HashMap::new();
+    let mut came_from: HashMap<Point, Point> = HashMap::new();
+
+    best.insert(start, 0);
+    open.push(Node {
+        estimate: heuristic(start),
+        cost: 0,
+        pos: start,
+    });
+
+    while let Some(Node {
+        cost: cur_cost,
+        pos,
+        ..
+    }) = open.pop()
+    {
+        if pos == goal {
+            return Some(Path {
+                points: reconstruct(&came_from, goal),
+                cost: cur_cost,
+            });
+        }
+        // Skip stale entries that have since been reached more cheaply.
+        if best.get(&pos).is_some_and(|&c| c < cur_cost) {
+            continue;
+        }
+
+        for next in neighbourhood.neighbours(pos) {
+            let Some(step) = cost(pos, next) else {
+                continue;
+            };
+            // Moves whose total cost cannot be represented are impassable.
+            let Some(next_cost) = cur_cost.checked_add(step) else {
+                continue;
+            };
+            if best.get(&next).is_none_or(|&c| next_cost < c) {
+                best.insert(next, next_cost);
+                came_from.insert(next, pos);
+                open.push(Node {
+                    estimate: next_cost.saturating_add(heuristic(next)),
+                    cost: next_cost,
+                    pos: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest path from start to goal using the
+/// [A* search algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
+///
+/// cost is called with the current point and one of its neighbours, and should
+/// return the cost of moving between them, or None if the move is impassable.
+/// Moves that would take the total cost of a path beyond u32::MAX are also
+/// treated as impassable. Returns None if goal cannot be reached.
+///
+/// The search is guided by the distance to goal under the given heuristic
+/// metric, rounded down. For the search to find an optimal path, this must
+/// never exceed the true cost of reaching the goal. With a cost of at least 1
+/// per move, [Manhattan](crate::metric::Manhattan) is suitable for a
+/// [Neighbourhood::Four] search, and [Chebyshev](crate::metric::Chebyshev) for
+/// a [Neighbourhood::Eight] search. If diagonal moves cost √2 times as much as
+/// orthogonal ones, [Octile](crate::metric::Octile) gives the best estimate.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::metric::Manhattan;
+/// use point::pathfinding::{Neighbourhood, astar};
+///
+/// // A wall at x = 2 with a gap at y = 4.
+/// let passable = |p: Point| p.bounds_check(5, 5) && (p.x != 2 || p.y == 4);
+///
+/// let path = astar(
+///     Point::new(0, 0),
+///     Point::new(4, 0),
+///     Neighbourhood::Four,
+///     Manhattan,
+///     |_, next| passable(next).then_some(1),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(path.cost, 12);
+/// assert_eq!(path.points.len(), 13);
+/// assert!(path.points.contains(&Point::new(2, 4)));
+/// ```
+pub fn astar<M, C>(
+    start: Point,
+    goal: Point,
+    neighbourhood: Neighbourhood,
+    heuristic: M,
+    cost: C,
+) -> Option<Path>
+where
+    M: Metric,
+    C: FnMut(Point, Point) -> Option<u32>,
+{
+    search(start, goal, neighbourhood, cost, |p| {
+        heuristic.dist(p, goal) as u32
+    })
+}
+
+/// Finds the cheapest path from start to goal using
+/// [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm).
+///
+/// cost is called with the current point and one of its neighbours, and should
+/// return the cost of moving between them, or None if the move is impassable.
+/// Moves that would take the total cost of a path beyond u32::MAX are also
+/// treated as impassable. Returns None if goal cannot be reached.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::pathfinding::{Neighbourhood, dijkstra};
+///
+/// // Moving through the marsh at y = 1 costs 5 rather than 1.
+/// let cost = |_, next: Point| {
+///     if !next.bounds_check(3, 3) {
+///         None
+///     } else if next.y == 1 {
+///         Some(5)
+///     } else {
+///         Some(1)
+///     }
+/// };
+///
+/// let path = dijkstra(Point::new(0, 0), Point::new(0, 2), Neighbourhood::Four, cost).unwrap();
+///
+/// assert_eq!(path.cost, 6);
+/// assert_eq!(path.points, vec![Point::new(0, 0), Point::new(0, 1), Point::new(0, 2)]);
+///
+/// // A path costing more than u32::MAX is never found.
+/// let huge = |_, next: Point| next.bounds_check(3, 1).then_some(u32::MAX / 2 + 1);
+/// assert_eq!(dijkstra(Point::new(0, 0), Point::new(2, 0), Neighbourhood::Four, huge), None);
+/// ```
+pub fn dijkstra<C>(start: Point, goal: Point, neighbourhood: Neighbourhood, cost: C) -> Option<Path>
+where
+    C: FnMut(Point, Point) -> Option<u32>,
+{
+    search(start, goal, neighbourhood, cost, |_| 0)
+}
+
+/// Finds the path from start to goal with the fewest moves using a
+/// [breadth first search](https://en.wikipedia.org/wiki/Breadth-first_search).
+///
+/// passable is called with each point the search wishes to enter. The cost of
+/// the returned path is the number of moves taken. Returns None if goal cannot
+/// be reached.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::pathfinding::{Neighbourhood, bfs};
+///
+/// let passable = |p: Point| p.bounds_check(10, 10);
+///
+/// let four = bfs(Point::new(0, 0), Point::new(3, 3), Neighbourhood::Four, passable).unwrap();
+/// let eight = bfs(Point::new(0, 0), Point::new(3, 3), Neighbourhood::Eight, passable).unwrap();
+///
+/// assert_eq!(four.cost, 6);
+/// assert_eq!(eight.cost, 3);
+/// assert_eq!(eight.points.last(), Some(&Point::new(3, 3)));
+/// ```
+pub fn bfs<P>(
+    start: Point,
+    goal: Point,
+    neighbourhood: Neighbourhood,
+    mut passable: P,
+) -> Option<Path>
+where
+    P: FnMut(Point) -> bool,
+{
+    let mut queue = VecDeque::from([start]);
+    let mut seen = HashSet::from([start]);
+    let mut came_from: HashMap<Point, Point> = HashMap::new();
+
+    while let Some(pos) = queue.pop_front() {
+        if pos == goal {
+            let points = reconstruct(&came_from, goal);
+            return Some(Path {
+                cost: points.len() as u32 - 1,
+                points,
+            });
+        }
+
+        for next in neighbourhood.neighbours(pos) {
+            if !seen.contains(&next) && passable(next) {
+                seen.insert(next);
+                came_from.insert(next, pos);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
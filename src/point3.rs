@@ -0,0 +1,666 @@
+//! A three dimensional counterpart to [Point].
+use crate::{
+    Rounding,
+    scalar::{Float, Integer, Scalar, Signed},
+};
+use std::{fmt, iter::FusedIterator, marker::PhantomData, ops};
+
+/// A 3D co-ordinate.
+///
+/// As with [Point](crate::Point), the co-ordinates may be of any primitive
+/// numeric type, defaulting to i32, and methods that only make sense for some
+/// types are only available when the co-ordinates implement the relevant trait
+/// from [scalar](crate::scalar).
+///
+/// # Examples
+///
+/// ```
+/// use point::{Axis, Point3};
+///
+/// let p = Point3::new(1, 2, 3);
+///
+/// assert_eq!(p + Point3::new(1, 1, 1), Point3::new(2, 3, 4));
+/// assert_eq!(p.manhattan_dist(Point3::ORIGIN), 6);
+/// assert_eq!(p.rotate_90_cw(Axis::Z), Point3::new(2, -1, 3));
+/// assert_eq!(p.adjacent_diagonal().len(), 26);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Point3<T = i32> {
+    /// X co-ordinate
+    pub x: T,
+    /// Y co-ordinate
+    pub y: T,
+    /// Z co-ordinate
+    pub z: T,
+}
+
+/// One of the three axes of 3D space, about which a [Point3] can be rotated.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    /// The axis along which only x changes.
+    X,
+    /// The axis along which only y changes.
+    Y,
+    /// The axis along which only z changes.
+    Z,
+}
+
+impl<T: Scalar> Point3<T> {
+    /// The point at (0, 0, 0).
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+
+    /// Return a new point instance with given x, y and z positions.
+    #[inline(always)]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the Euclidean distance between self and other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// assert_eq!(Point3::new(1, 2, 3).dist(Point3::new(3, 5, 9)), 7.0);
+    ///
+    /// let (min, max) = (Point3::new(i32::MIN, 0, 0), Point3::new(i32::MAX, 0, 0));
+    /// assert_eq!(min.dist(max), u32::MAX as f64);
+    /// ```
+    #[inline]
+    pub fn dist(self, other: Self) -> f64 {
+        let dx = self.x.abs_difference_f64(other.x);
+        let dy = self.y.abs_difference_f64(other.y);
+        let dz = self.z.abs_difference_f64(other.z);
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    /// Returns the squared Euclidean distance between self and other.
+    /// This is more performant than dist as the square root
+    /// step is skipped.
+    #[inline]
+    pub fn dist_squared(self, other: Self) -> T {
+        let dx = self.x.abs_difference(other.x);
+        let dy = self.y.abs_difference(other.y);
+        let dz = self.z.abs_difference(other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+
+    /// Returns the manhattan distance between self and other, saturating at
+    /// the largest value of T. For i32 co-ordinates, use
+    /// [Point3::manhattan_dist_wide] if the points may be further apart.
+    #[inline]
+    pub fn manhattan_dist(self, other: Self) -> T {
+        self.x
+            .abs_difference(other.x)
+            .saturating_add(self.y.abs_difference(other.y))
+            .saturating_add(self.z.abs_difference(other.z))
+    }
+
+    /// Returns the Chebyshev distance between self and other; the largest of
+    /// the differences between their co-ordinates. This is the number of moves
+    /// between them when any of the moves given by
+    /// [Point3::adjacent_diagonal] are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// assert_eq!(Point3::new(0, 0, 0).chebyshev_dist(Point3::new(2, -5, 3)), 5);
+    /// ```
+    #[inline]
+    pub fn chebyshev_dist(self, other: Self) -> T {
+        let dx = self.x.abs_difference(other.x);
+        let dy = self.y.abs_difference(other.y);
+        let dz = self.z.abs_difference(other.z);
+        let dxy = if dx > dy { dx } else { dy };
+        if dxy > dz { dxy } else { dz }
+    }
+
+    /// Returns self ⋅ other; that is, the [dot product](https://en.wikipedia.org/wiki/Dot_product), interpreting
+    /// self and other as 3D vectors.
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl<T: Signed> Point3<T> {
+    /// Returns self × other; that is, the [cross product](https://en.wikipedia.org/wiki/Cross_product),
+    /// interpreting self and other as 3D vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// let x = Point3::new(1, 0, 0);
+    /// let y = Point3::new(0, 1, 0);
+    ///
+    /// assert_eq!(x.cross(y), Point3::new(0, 0, 1));
+    /// assert_eq!(y.cross(x), Point3::new(0, 0, -1));
+    /// ```
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Returns the co-ordinate rotated by 90 degrees clockwise about the given
+    /// axis, looking from the positive end of the axis towards the origin.
+    /// Rotating about [Axis::Z] is the same as [Point::rotate_90_cw](crate::Point::rotate_90_cw)
+    /// on the x and y co-ordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Axis, Point3};
+    ///
+    /// let p = Point3::new(1, 2, 3);
+    ///
+    /// assert_eq!(p.rotate_90_cw(Axis::X), Point3::new(1, 3, -2));
+    /// assert_eq!(p.rotate_90_cw(Axis::Y), Point3::new(-3, 2, 1));
+    /// assert_eq!(p.rotate_90_cw(Axis::Z), Point3::new(2, -1, 3));
+    /// ```
+    #[inline]
+    pub fn rotate_90_cw(self, axis: Axis) -> Self {
+        let Self { x, y, z } = self;
+        match axis {
+            Axis::X => Self::new(x, z, -y),
+            Axis::Y => Self::new(-z, y, x),
+            Axis::Z => Self::new(y, -x, z),
+        }
+    }
+
+    /// Returns the co-ordinate rotated by 90 degrees anti-clockwise about the
+    /// given axis, looking from the positive end of the axis towards the
+    /// origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Axis, Point3};
+    ///
+    /// let p = Point3::new(1, 2, 3);
+    ///
+    /// for axis in [Axis::X, Axis::Y, Axis::Z] {
+    ///     assert_eq!(p.rotate_90_cw(axis).rotate_90_acw(axis), p);
+    /// }
+    /// ```
+    #[inline]
+    pub fn rotate_90_acw(self, axis: Axis) -> Self {
+        let Self { x, y, z } = self;
+        match axis {
+            Axis::X => Self::new(x, -z, y),
+            Axis::Y => Self::new(z, y, -x),
+            Axis::Z => Self::new(-y, x, z),
+        }
+    }
+
+    /// Returns the co-ordinate rotated by 180 degrees about the given axis.
+    #[inline]
+    pub fn rotate_180(self, axis: Axis) -> Self {
+        let Self { x, y, z } = self;
+        match axis {
+            Axis::X => Self::new(x, -y, -z),
+            Axis::Y => Self::new(-x, y, -z),
+            Axis::Z => Self::new(-x, -y, z),
+        }
+    }
+}
+
+impl<T: Float> Point3<T> {
+    /// Returns the length of the point interpreted as a vector; that is,
+    /// its Euclidean distance from the origin.
+    #[inline]
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the point scaled to have a length of 1, interpreting it as a
+    /// vector. Normalizing the origin results in NaN co-ordinates.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    /// Linearly interpolates between self and other. A t of 0 returns self,
+    /// and a t of 1 returns other.
+    #[inline]
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Converts the point into a point with i32 co-ordinates, rounding each
+    /// co-ordinate as specified. Co-ordinates outside of the range of i32
+    /// saturate, and NaN becomes 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point3, Rounding};
+    ///
+    /// let p = Point3::new(1.5, -2.5, 0.2);
+    ///
+    /// assert_eq!(p.to_point3(Rounding::Floor), Point3::new(1, -3, 0));
+    /// assert_eq!(p.to_point3(Rounding::Round), Point3::new(2, -3, 0));
+    /// ```
+    pub fn to_point3(self, rounding: Rounding) -> Point3 {
+        Point3::new(
+            rounding.apply(self.x).to_f64() as i32,
+            rounding.apply(self.y).to_f64() as i32,
+            rounding.apply(self.z).to_f64() as i32,
+        )
+    }
+}
+
+impl<T: Integer> Point3<T> {
+    /// Returns an iterator over all the points on the line between src and
+    /// dest, using the 3D equivalent of [Point::plot_line](crate::Point::plot_line).
+    /// Note that src is included in the line, but dest is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// let line: Vec<Point3> = Point3::plot_line(Point3::new(0, 0, 0), Point3::new(4, 2, 1)).collect();
+    ///
+    /// let expected: Vec<Point3> = vec![(0, 0, 0), (1, 1, 0), (2, 1, 1), (3, 2, 1)].into_iter().map(Point3::from).collect();
+    /// assert_eq!(line, expected);
+    /// ```
+    pub fn plot_line(src: Self, dest: Self) -> Line3Iter<T> {
+        Line3Iter::new(src, dest, false)
+    }
+
+    /// The same as plot_line, but dest is also included in the line.
+    ///
+    /// Lines are symmetric, so the points from src to dest are exactly the
+    /// points from dest to src in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// let (a, b) = (Point3::new(-2, 5, 1), Point3::new(3, -1, 4));
+    /// let forward: Vec<Point3> = Point3::plot_line_inclusive(a, b).collect();
+    /// let backward: Vec<Point3> = Point3::plot_line_inclusive(b, a).rev().collect();
+    ///
+    /// assert_eq!(forward, backward);
+    /// assert_eq!(forward.len(), 7);
+    /// assert!(forward.windows(2).all(|w| w[0].chebyshev_dist(w[1]) == 1));
+    /// ```
+    pub fn plot_line_inclusive(src: Self, dest: Self) -> Line3Iter<T> {
+        Line3Iter::new(src, dest, true)
+    }
+}
+
+impl Point3 {
+    /// Returns the six points sharing a face with the point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// let p = Point3::new(0, 0, 0);
+    ///
+    /// let expected = [(1, 0, 0), (-1, 0, 0), (0, 1, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1)].map(Point3::from);
+    /// assert_eq!(p.adjacent(), expected);
+    /// ```
+    #[inline]
+    pub const fn adjacent(&self) -> [Self; 6] {
+        let Self { x, y, z } = *self;
+        [
+            Self::new(x + 1, y, z),
+            Self::new(x - 1, y, z),
+            Self::new(x, y + 1, z),
+            Self::new(x, y - 1, z),
+            Self::new(x, y, z + 1),
+            Self::new(x, y, z - 1),
+        ]
+    }
+
+    /// The same as adjacent, but also returns the twelve points sharing an
+    /// edge with the point. The points are ordered by x co-ordinate, then by
+    /// y co-ordinate, then by z co-ordinate.
+    pub const fn adjacent_edge(&self) -> [Self; 18] {
+        self.adjacent_within(2)
+    }
+
+    /// The same as adjacent_edge, but also returns the eight points sharing a
+    /// corner with the point.
+    pub const fn adjacent_diagonal(&self) -> [Self; 26] {
+        self.adjacent_within(3)
+    }
+
+    /// Returns every point in the 3x3x3 cube around the point, except the
+    /// point itself, that differs from it in no more than max_axes
+    /// co-ordinates. N must be the number of such points.
+    const fn adjacent_within<const N: usize>(&self, max_axes: i32) -> [Self; N] {
+        let mut points = [*self; N];
+        let mut i = 0;
+        let mut offset = 0;
+        while offset < 27 {
+            let (x, y, z) = (offset / 9 - 1, offset / 3 % 3 - 1, offset % 3 - 1);
+            let axes = x * x + y * y + z * z;
+            if axes != 0 && axes <= max_axes {
+                points[i] = Self::new(self.x + x, self.y + y, self.z + z);
+                i += 1;
+            }
+            offset += 1;
+        }
+        points
+    }
+
+    /// Returns the squared Euclidean distance between self and other as a
+    /// u128, which cannot overflow for any pair of points.
+    #[inline]
+    pub const fn dist_squared_wide(self, other: Self) -> u128 {
+        let dx = self.x.abs_diff(other.x) as u128;
+        let dy = self.y.abs_diff(other.y) as u128;
+        let dz = self.z.abs_diff(other.z) as u128;
+        dx * dx + dy * dy + dz * dz
+    }
+
+    /// Returns the manhattan distance between self and other as a u64,
+    /// which cannot overflow for any pair of points.
+    #[inline]
+    pub const fn manhattan_dist_wide(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) as u64
+            + self.y.abs_diff(other.y) as u64
+            + self.z.abs_diff(other.z) as u64
+    }
+
+    /// Helper for converting an index into a three dimensional co-ordinate
+    /// given the width and height of the space. It is assumed that the index
+    /// is within the depth boundary, so it is not required as an argument.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// let (width, height) = (4, 3);
+    ///
+    /// // Each layer holds 12 points, so index 17 is 5 points into the
+    /// // second layer.
+    /// assert_eq!(Point3::convert_up(17, width, height), Point3::new(1, 1, 1));
+    /// ```
+    pub const fn convert_up(index: usize, width: usize, height: usize) -> Point3 {
+        let layer = width * height;
+        Point3 {
+            x: (index % width) as i32,
+            y: (index % layer / width) as i32,
+            z: (index / layer) as i32,
+        }
+    }
+
+    /// Performs the inverse of convert_up; turns a point into an index
+    /// given a width and height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// let p = Point3::new(1, 1, 1);
+    ///
+    /// assert_eq!(p.convert_down(4, 3), 17);
+    /// assert_eq!(Point3::convert_up(p.convert_down(4, 3), 4, 3), p);
+    /// ```
+    pub const fn convert_down(&self, width: usize, height: usize) -> usize {
+        (self.z as usize * height + self.y as usize) * width + self.x as usize
+    }
+
+    /// Returns true if each co-ordinate of the point is greater than or equal
+    /// to zero and less than the corresponding maximum. In all other cases,
+    /// returns false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point3;
+    ///
+    /// let p = Point3::new(4, 5, 6);
+    ///
+    /// assert!(p.bounds_check(10, 10, 10));
+    /// assert!(!p.bounds_check(10, 10, 6));
+    /// assert!(!Point3::new(0, -1, 0).bounds_check(10, 10, 10));
+    /// ```
+    pub const fn bounds_check(&self, max_x: i32, max_y: i32, max_z: i32) -> bool {
+        0 <= self.x
+            && self.x < max_x
+            && 0 <= self.y
+            && self.y < max_y
+            && 0 <= self.z
+            && self.z < max_z
+    }
+}
+
+impl<T: Scalar> ops::Add for Point3<T> {
+    type Output = Self;
+
+    /// Returns a new point containing the sum of the points' co-ordinates.
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Sub for Point3<T> {
+    type Output = Self;
+
+    /// Returns a new point containing the difference of the points' co-ordinates.
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl<T: Signed> ops::Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Div<T> for Point3<T> {
+    type Output = Self;
+
+    /// Returns a new point containg each co-ordinate divided by the given divisor.
+    fn div(self, other: T) -> Self::Output {
+        Self {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl<T: Scalar> ops::Mul<T> for Point3<T> {
+    type Output = Self;
+
+    /// Returns a new point containg each co-ordinate multiplied by the given multiplier.
+    fn mul(self, other: T) -> Self::Output {
+        Self {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    /// Formats the point as `(x, y, z)`, or as `x,y,z` if the alternate flag
+    /// is specified.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{},{},{}", self.x, self.y, self.z)
+        } else {
+            write!(f, "({}, {}, {})", self.x, self.y, self.z)
+        }
+    }
+}
+
+impl From<Point3> for Point3<f64> {
+    fn from(val: Point3) -> Self {
+        Self {
+            x: val.x.into(),
+            y: val.y.into(),
+            z: val.z.into(),
+        }
+    }
+}
+
+impl<T, S> From<(S, S, S)> for Point3<T>
+where
+    S: Into<T>,
+{
+    fn from(val: (S, S, S)) -> Self {
+        Self {
+            x: val.0.into(),
+            y: val.1.into(),
+            z: val.2.into(),
+        }
+    }
+}
+
+impl<T, U> From<Point3<T>> for (U, U, U)
+where
+    T: Into<U>,
+{
+    fn from(val: Point3<T>) -> Self {
+        (val.x.into(), val.y.into(), val.z.into())
+    }
+}
+
+/// An iterator over the points on a line between two points in 3D space.
+///
+/// As with [LineIter](crate::LineIter), the line steps one point at a time
+/// along its major axis, with the positions on the other two axes rounded to
+/// the nearest integer, and is always plotted starting from the lesser of its
+/// two end points.
+///
+/// As with [LineIter](crate::LineIter), the [len](ExactSizeIterator::len)
+/// of a line saturates at usize::MAX.
+#[derive(Clone, Debug)]
+pub struct Line3Iter<T = i32> {
+    start: [i128; 3],
+    disp: [i128; 3],
+    major: usize,
+    len: i128,
+    reversed: bool,
+    front: i128,
+    back: i128,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Integer> Line3Iter<T> {
+    fn new(src: Point3<T>, dest: Point3<T>, inclusive: bool) -> Self {
+        let src = [src.x.to_i128(), src.y.to_i128(), src.z.to_i128()];
+        let dest = [dest.x.to_i128(), dest.y.to_i128(), dest.z.to_i128()];
+        let reversed = dest < src;
+        let (start, end) = if reversed { (dest, src) } else { (src, dest) };
+
+        let disp = [end[0] - start[0], end[1] - start[1], end[2] - start[2]];
+        let mut major = 0;
+        for axis in 1..3 {
+            if disp[axis].abs() > disp[major].abs() {
+                major = axis;
+            }
+        }
+        let len = disp[major].abs();
+
+        Self {
+            start,
+            disp,
+            major,
+            len,
+            reversed,
+            front: 0,
+            back: if inclusive { len + 1 } else { len },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the point at the given position, counting from src.
+    fn point_at(&self, pos: i128) -> Point3<T> {
+        let i = if self.reversed { self.len - pos } else { pos };
+        let coord = |axis: usize| {
+            let offset = if axis == self.major {
+                i * self.disp[axis].signum()
+            } else if self.len == 0 {
+                0
+            } else {
+                // Rounds i * disp / len to the nearest integer, rounding ties
+                // towards positive infinity.
+                (2 * i * self.disp[axis] + self.len).div_euclid(2 * self.len)
+            };
+            T::from_i128(self.start[axis] + offset)
+        };
+
+        Point3::new(coord(0), coord(1), coord(2))
+    }
+}
+
+impl<T: Integer> Iterator for Line3Iter<T> {
+    type Item = Point3<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let p = self.point_at(self.front);
+        self.front += 1;
+        Some(p)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front).max(0);
+        match usize::try_from(remaining) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, Some(usize::MAX)),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n as i128).min(self.back);
+        self.next()
+    }
+}
+
+impl<T: Integer> DoubleEndedIterator for Line3Iter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.point_at(self.back))
+    }
+}
+
+impl<T: Integer> ExactSizeIterator for Line3Iter<T> {}
+
+impl<T: Integer> FusedIterator for Line3Iter<T> {}
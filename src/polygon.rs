@@ -0,0 +1,309 @@
+//! Filling and measuring polygons given as slices of [Point]s.
+//!
+//! A polygon is given by its vertices in order, with an edge from each vertex
+//! to the next and from the last vertex back to the first. Polygons may be
+//! concave or self-intersecting, and all calculations are exact.
+//!
+//! Filling treats each point as a sample at the centre of its cell, and
+//! includes the points strictly inside the polygon. Points lying exactly on
+//! an edge are included if the inside of the polygon is towards positive x or
+//! positive y from them, and excluded otherwise. In the usual raster graphics
+//! convention, with the y axis pointing down, this is the top-left rule. It
+//! means that polygons sharing an edge never both include a point on it, and
+//! that a rectangle's corners give the same points as the half-open [Rect](crate::Rect)
+//! with those corners.
+use crate::Point;
+
+/// Decides which points are inside a self-intersecting polygon, or one with
+/// holes.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FillRule {
+    /// Points are inside if a ray from them crosses the polygon's edges an
+    /// odd number of times. Overlapping parts of the polygon cancel out.
+    EvenOdd,
+    /// Points are inside if the polygon winds around them a non-zero number
+    /// of times. Overlapping parts of the polygon are filled.
+    #[default]
+    NonZero,
+}
+
+/// The order in which a polygon's vertices go around it, when the y axis
+/// points upwards.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Winding {
+    /// The vertices go clockwise, and the signed area is negative.
+    Clockwise,
+    /// The vertices go anti-clockwise, and the signed area is positive.
+    AntiClockwise,
+}
+
+/// Returns an iterator over the edges of the polygon, as pairs of vertices.
+fn edges(polygon: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Returns the cross product of b - a and c - a, which is positive if c is
+/// anti-clockwise of b about a.
+fn cross(a: Point, b: Point, c: Point) -> i128 {
+    let (abx, aby) = (b.x as i128 - a.x as i128, b.y as i128 - a.y as i128);
+    let (acx, acy) = (c.x as i128 - a.x as i128, c.y as i128 - a.y as i128);
+    abx * acy - aby * acx
+}
+
+/// Returns twice the signed area of the polygon, using the shoelace formula.
+///
+/// The area is positive if the polygon winds anti-clockwise, and negative if
+/// it winds clockwise. Twice the area is always an integer, so no precision is
+/// lost. Parts of a self-intersecting polygon that wind in opposite directions
+/// cancel out.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, polygon};
+///
+/// let triangle = [Point::new(0, 0), Point::new(3, 0), Point::new(0, 3)];
+///
+/// assert_eq!(polygon::double_area(&triangle), 9);
+/// assert_eq!(polygon::double_area(&[triangle[0], triangle[2], triangle[1]]), -9);
+/// ```
+pub fn double_area(polygon: &[Point]) -> i128 {
+    edges(polygon)
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum()
+}
+
+/// Returns the area of the polygon. See [double_area] for details.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, polygon};
+///
+/// let square = [Point::new(0, 0), Point::new(0, 2), Point::new(2, 2), Point::new(2, 0)];
+///
+/// assert_eq!(polygon::area(&square), 4.0);
+/// ```
+pub fn area(polygon: &[Point]) -> f64 {
+    double_area(polygon).unsigned_abs() as f64 / 2.0
+}
+
+/// Returns the order of the polygon's vertices, or None if its signed area is
+/// zero.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, polygon::{self, Winding}};
+///
+/// let triangle = [Point::new(0, 0), Point::new(3, 0), Point::new(0, 3)];
+///
+/// assert_eq!(polygon::winding(&triangle), Some(Winding::AntiClockwise));
+/// assert_eq!(polygon::winding(&[Point::new(0, 0), Point::new(1, 1)]), None);
+/// ```
+pub fn winding(polygon: &[Point]) -> Option<Winding> {
+    match double_area(polygon) {
+        0 => None,
+        area if area > 0 => Some(Winding::AntiClockwise),
+        _ => Some(Winding::Clockwise),
+    }
+}
+
+/// Returns the number of times the polygon winds anti-clockwise around p,
+/// which is negative if it winds clockwise.
+///
+/// Points on an edge follow the rule described in the [module](self)
+/// documentation.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, polygon};
+///
+/// let triangle = [Point::new(0, 0), Point::new(4, 0), Point::new(0, 4)];
+///
+/// assert_eq!(polygon::winding_number(&triangle, Point::new(1, 1)), 1);
+/// assert_eq!(polygon::winding_number(&triangle, Point::new(3, 3)), 0);
+/// ```
+pub fn winding_number(polygon: &[Point], p: Point) -> i32 {
+    edges(polygon)
+        .filter_map(|(a, b)| crossing(a, b, p.y))
+        .filter(|crossing| crossing.x <= p.x)
+        .map(|crossing| crossing.winding)
+        .sum()
+}
+
+/// Returns true if p is inside the polygon under the given fill rule.
+///
+/// Points on an edge follow the rule described in the [module](self)
+/// documentation, so the result agrees with [fill].
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, polygon::{self, FillRule}};
+///
+/// // A five pointed star, whose centre is wound around twice.
+/// let star = [Point::new(0, 10), Point::new(6, -8), Point::new(-10, 3), Point::new(10, 3), Point::new(-6, -8)];
+///
+/// assert!(polygon::contains(&star, Point::new(0, 0), FillRule::NonZero));
+/// assert!(!polygon::contains(&star, Point::new(0, 0), FillRule::EvenOdd));
+/// assert!(polygon::contains(&star, Point::new(0, 7), FillRule::EvenOdd));
+/// ```
+pub fn contains(polygon: &[Point], p: Point, rule: FillRule) -> bool {
+    let winding = winding_number(polygon, p);
+    match rule {
+        FillRule::EvenOdd => winding % 2 != 0,
+        FillRule::NonZero => winding != 0,
+    }
+}
+
+/// The point at which an edge crosses a row.
+struct Crossing {
+    /// The least x co-ordinate at or to the right of the crossing.
+    x: i32,
+    /// The change in winding number from crossing the edge towards positive
+    /// x: 1 if the edge goes downwards, or -1 if it goes upwards.
+    winding: i32,
+}
+
+/// Returns where the edge from a to b crosses the row at y, if it does.
+///
+/// Edges include their lower end but not their upper end, so that a row
+/// passing through a vertex is only counted once, and horizontal edges are
+/// never crossed.
+fn crossing(a: Point, b: Point, y: i32) -> Option<Crossing> {
+    let (lo, hi, winding) = if a.y < b.y { (a, b, -1) } else { (b, a, 1) };
+    if y < lo.y || y >= hi.y {
+        return None;
+    }
+
+    // x = lo.x + (y - lo.y) * (hi.x - lo.x) / (hi.y - lo.y), rounded up.
+    let num = (y as i128 - lo.y as i128) * (hi.x as i128 - lo.x as i128);
+    let den = hi.y as i128 - lo.y as i128;
+    let x = lo.x as i128 - (-num).div_euclid(den);
+
+    Some(Crossing {
+        x: x as i32,
+        winding,
+    })
+}
+
+/// Returns every point inside the polygon under the given fill rule, in
+/// row-major order.
+///
+/// Each row of the polygon is scanned for the points at which it crosses the
+/// polygon's edges, and the spans between them that are inside the polygon
+/// are filled. Points on an edge follow the rule described in the
+/// [module](self) documentation.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, polygon::{self, FillRule}};
+///
+/// let square = [Point::new(0, 0), Point::new(3, 0), Point::new(3, 3), Point::new(0, 3)];
+/// let filled = polygon::fill(&square, FillRule::NonZero);
+///
+/// // Matches the half-open rect with the same corners.
+/// let expected: Vec<Point> = point::Rect::new(Point::new(0, 0), Point::new(3, 3)).points().collect();
+/// assert_eq!(filled, expected);
+///
+/// // Overlapping squares, the second inside the first.
+/// let nested = [
+///     Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4), Point::new(0, 0),
+///     Point::new(1, 1), Point::new(3, 1), Point::new(3, 3), Point::new(1, 3), Point::new(1, 1),
+/// ];
+///
+/// assert_eq!(polygon::fill(&nested, FillRule::NonZero).len(), 16);
+/// assert_eq!(polygon::fill(&nested, FillRule::EvenOdd).len(), 12);
+/// ```
+pub fn fill(polygon: &[Point], rule: FillRule) -> Vec<Point> {
+    let mut points = Vec::new();
+    let Some(min_y) = polygon.iter().map(|p| p.y).min() else {
+        return points;
+    };
+    let max_y = polygon.iter().map(|p| p.y).max().unwrap_or(min_y);
+
+    let mut crossings = Vec::new();
+    for y in min_y..max_y {
+        crossings.clear();
+        crossings.extend(edges(polygon).filter_map(|(a, b)| crossing(a, b, y)));
+        crossings.sort_unstable_by_key(|crossing| crossing.x);
+
+        let mut winding = 0;
+        for pair in crossings.windows(2) {
+            winding += pair[0].winding;
+            let inside = match rule {
+                FillRule::EvenOdd => winding % 2 != 0,
+                FillRule::NonZero => winding != 0,
+            };
+            if inside {
+                points.extend((pair[0].x..pair[1].x).map(|x| Point::new(x, y)));
+            }
+        }
+    }
+
+    points
+}
+
+/// Returns every point inside the triangle with the given vertices, in
+/// row-major order.
+///
+/// The vertices may be given in either order. Points on an edge follow the
+/// top-left rule described in the [module](self) documentation, so triangles
+/// sharing an edge never overlap, and the result is the same as filling the
+/// triangle with [fill]. A triangle with no area contains no points.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, polygon::{self, FillRule}};
+///
+/// let (a, b, c) = (Point::new(0, 0), Point::new(4, 0), Point::new(4, 4));
+/// let d = Point::new(0, 4);
+///
+/// let lower = polygon::fill_triangle(a, b, c);
+/// let upper = polygon::fill_triangle(a, c, d);
+///
+/// assert_eq!(lower, polygon::fill(&[a, b, c], FillRule::NonZero));
+/// assert!(lower.iter().all(|p| !upper.contains(p)));
+/// assert_eq!(lower.len() + upper.len(), 16);
+/// ```
+pub fn fill_triangle(a: Point, b: Point, c: Point) -> Vec<Point> {
+    let mut points = Vec::new();
+    let (b, c) = match cross(a, b, c) {
+        0 => return points,
+        area if area > 0 => (b, c),
+        _ => (c, b),
+    };
+
+    // With the vertices anti-clockwise, the inside is to the left of each
+    // edge. Points on an edge are included if the inside is towards positive
+    // x or y of it, as for edges going down, or going right along a row.
+    let edges = [(a, b), (b, c), (c, a)].map(|(from, to)| {
+        let inclusive = from.y > to.y || (from.y == to.y && from.x < to.x);
+        (from, to, if inclusive { 0 } else { 1 })
+    });
+
+    let min = Point::new(a.x.min(b.x).min(c.x), a.y.min(b.y).min(c.y));
+    let max = Point::new(a.x.max(b.x).max(c.x), a.y.max(b.y).max(c.y));
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let p = Point::new(x, y);
+            if edges
+                .iter()
+                .all(|&(from, to, bias)| cross(from, to, p) >= bias)
+            {
+                points.push(p);
+            }
+        }
+    }
+
+    points
+}
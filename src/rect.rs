@@ -0,0 +1,495 @@
+//! Axis-aligned rectangles of [Point]s.
+use crate::Point;
+use std::iter::FusedIterator;
+
+/// An axis-aligned rectangle, containing every point whose x co-ordinate is
+/// in `min.x..max.x` and whose y co-ordinate is in `min.y..max.y`.
+///
+/// Like [Point::bounds_check], the minimum is inclusive and the maximum is
+/// exclusive. A rectangle where either component of max is not greater than
+/// the corresponding component of min contains no points.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, Rect};
+///
+/// let rect = Rect::from_size(Point::new(1, 1), 3, 2);
+///
+/// assert!(rect.contains(Point::new(3, 2)));
+/// assert!(!rect.contains(Point::new(4, 2)));
+/// assert_eq!(rect.area(), 6);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    /// The inclusive minimum corner.
+    pub min: Point,
+    /// The exclusive maximum corner.
+    pub max: Point,
+}
+
+impl Rect {
+    /// Returns a new rect with the given inclusive minimum and exclusive
+    /// maximum corners.
+    #[inline(always)]
+    pub const fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns a new rect with its minimum corner at origin and the given
+    /// width and height.
+    ///
+    /// # Panics
+    ///
+    /// The maximum corner overflows if it lies beyond i32::MAX, which panics
+    /// in debug builds.
+    #[inline]
+    pub const fn from_size(origin: Point, width: i32, height: i32) -> Self {
+        Self {
+            min: origin,
+            max: Point::new(origin.x + width, origin.y + height),
+        }
+    }
+
+    /// Returns the smallest rect containing both a and b.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let rect = Rect::from_corners(Point::new(4, 0), Point::new(1, 2));
+    ///
+    /// assert_eq!(rect, Rect::new(Point::new(1, 0), Point::new(5, 3)));
+    /// ```
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        Self {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x) + 1, a.y.max(b.y) + 1),
+        }
+    }
+
+    /// Returns the number of columns in the rect, or 0 if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// The result overflows if the rect is more than i32::MAX columns wide,
+    /// which panics in debug builds. [Rect::area] never overflows.
+    #[inline]
+    pub const fn width(&self) -> i32 {
+        if self.max.x > self.min.x {
+            self.max.x - self.min.x
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of rows in the rect, or 0 if it is empty.
+    ///
+    /// # Panics
+    ///
+    /// The result overflows if the rect is more than i32::MAX rows tall,
+    /// which panics in debug builds.
+    #[inline]
+    pub const fn height(&self) -> i32 {
+        if self.max.y > self.min.y {
+            self.max.y - self.min.y
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of columns in the rect as a u64, which cannot
+    /// overflow.
+    #[inline]
+    pub(crate) const fn wide_width(&self) -> u64 {
+        span(self.min.x, self.max.x)
+    }
+
+    /// Returns the number of rows in the rect as a u64, which cannot
+    /// overflow.
+    #[inline]
+    pub(crate) const fn wide_height(&self) -> u64 {
+        span(self.min.y, self.max.y)
+    }
+
+    /// Returns the number of points contained in the rect, saturating at
+    /// i64::MAX for rects spanning nearly the whole of the plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let row = Rect::new(Point::new(i32::MIN, 0), Point::new(i32::MAX, 2));
+    ///
+    /// assert_eq!(row.area(), 2 * u32::MAX as i64);
+    /// ```
+    #[inline]
+    pub const fn area(&self) -> i64 {
+        let area = self.wide_width() * self.wide_height();
+        if area > i64::MAX as u64 {
+            i64::MAX
+        } else {
+            area as i64
+        }
+    }
+
+    /// Returns true if the rect contains no points.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.max.x <= self.min.x || self.max.y <= self.min.y
+    }
+
+    /// Returns the point in the middle of the rect, rounding towards the
+    /// minimum corner.
+    #[inline]
+    pub const fn center(&self) -> Point {
+        Point::new(
+            (self.min.x as i64 + (self.wide_width() / 2) as i64) as i32,
+            (self.min.y as i64 + (self.wide_height() / 2) as i64) as i32,
+        )
+    }
+
+    /// Returns true if the point lies within the rect.
+    #[inline]
+    pub const fn contains(&self, pos: Point) -> bool {
+        self.min.x <= pos.x && pos.x < self.max.x && self.min.y <= pos.y && pos.y < self.max.y
+    }
+
+    /// Returns true if every point in other is also in self. An empty rect is
+    /// contained by every rect.
+    pub const fn contains_rect(&self, other: &Self) -> bool {
+        other.is_empty()
+            || (self.min.x <= other.min.x
+                && other.max.x <= self.max.x
+                && self.min.y <= other.min.y
+                && other.max.y <= self.max.y)
+    }
+
+    /// Returns true if self and other have at least one point in common.
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersect(other).is_some()
+    }
+
+    /// Returns the rect containing the points that are in both self and
+    /// other, or None if there are no such points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let r1 = Rect::new(Point::new(0, 0), Point::new(4, 4));
+    /// let r2 = Rect::new(Point::new(2, 3), Point::new(6, 6));
+    /// let r3 = Rect::new(Point::new(4, 0), Point::new(6, 2));
+    ///
+    /// assert_eq!(r1.intersect(&r2), Some(Rect::new(Point::new(2, 3), Point::new(4, 4))));
+    /// assert_eq!(r1.intersect(&r3), None);
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let rect = Self {
+            min: Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            max: Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        };
+
+        if rect.is_empty() { None } else { Some(rect) }
+    }
+
+    /// Returns the smallest rect containing both self and other. Empty rects
+    /// are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let r1 = Rect::new(Point::new(0, 0), Point::new(2, 2));
+    /// let r2 = Rect::new(Point::new(3, 1), Point::new(5, 4));
+    ///
+    /// assert_eq!(r1.union(&r2), Rect::new(Point::new(0, 0), Point::new(5, 4)));
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            *other
+        } else if other.is_empty() {
+            *self
+        } else {
+            Self {
+                min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+                max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+            }
+        }
+    }
+
+    /// Returns the point in the rect closest to pos. If pos lies within the
+    /// rect, it is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rect is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let rect = Rect::new(Point::new(0, 0), Point::new(5, 5));
+    ///
+    /// assert_eq!(rect.clamp_point(Point::new(7, -2)), Point::new(4, 0));
+    /// assert_eq!(rect.clamp_point(Point::new(2, 3)), Point::new(2, 3));
+    /// ```
+    pub fn clamp_point(&self, pos: Point) -> Point {
+        assert!(!self.is_empty(), "cannot clamp a point to an empty rect");
+        Point::new(
+            pos.x.clamp(self.min.x, self.max.x - 1),
+            pos.y.clamp(self.min.y, self.max.y - 1),
+        )
+    }
+
+    /// Returns the rect grown by amount in every direction. A negative amount
+    /// shrinks the rect instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let rect = Rect::new(Point::new(2, 2), Point::new(4, 5));
+    ///
+    /// assert_eq!(rect.inflate(1), Rect::new(Point::new(1, 1), Point::new(5, 6)));
+    /// assert!(rect.inflate(-1).is_empty());
+    /// ```
+    pub const fn inflate(&self, amount: i32) -> Self {
+        Self {
+            min: Point::new(self.min.x - amount, self.min.y - amount),
+            max: Point::new(self.max.x + amount, self.max.y + amount),
+        }
+    }
+
+    /// Returns the rect moved by offset.
+    #[inline]
+    pub const fn translate(&self, offset: Point) -> Self {
+        Self {
+            min: Point::new(self.min.x + offset.x, self.min.y + offset.y),
+            max: Point::new(self.max.x + offset.x, self.max.y + offset.y),
+        }
+    }
+
+    /// Splits the rect into the part left of the column x and the part
+    /// including and right of it. Returns None unless both parts would be
+    /// non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let rect = Rect::new(Point::new(0, 0), Point::new(10, 4));
+    /// let (left, right) = rect.split_at_x(3).unwrap();
+    ///
+    /// assert_eq!(left, Rect::new(Point::new(0, 0), Point::new(3, 4)));
+    /// assert_eq!(right, Rect::new(Point::new(3, 0), Point::new(10, 4)));
+    /// assert!(rect.split_at_x(0).is_none());
+    /// ```
+    pub fn split_at_x(&self, x: i32) -> Option<(Self, Self)> {
+        if self.is_empty() || x <= self.min.x || x >= self.max.x {
+            return None;
+        }
+        Some((
+            Self::new(self.min, Point::new(x, self.max.y)),
+            Self::new(Point::new(x, self.min.y), self.max),
+        ))
+    }
+
+    /// Splits the rect into the part above the row y and the part including
+    /// and below it. Returns None unless both parts would be non-empty.
+    pub fn split_at_y(&self, y: i32) -> Option<(Self, Self)> {
+        if self.is_empty() || y <= self.min.y || y >= self.max.y {
+            return None;
+        }
+        Some((
+            Self::new(self.min, Point::new(self.max.x, y)),
+            Self::new(Point::new(self.min.x, y), self.max),
+        ))
+    }
+
+    /// Splits the rect in half across its longest side, which is useful when
+    /// recursively partitioning space. The first part is the smaller when the
+    /// length of the side is odd. Returns None if the rect is a single point
+    /// or empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let rect = Rect::new(Point::new(0, 0), Point::new(4, 7));
+    /// let (top, bottom) = rect.split_half().unwrap();
+    ///
+    /// assert_eq!(top, Rect::new(Point::new(0, 0), Point::new(4, 3)));
+    /// assert_eq!(bottom, Rect::new(Point::new(0, 3), Point::new(4, 7)));
+    /// ```
+    pub fn split_half(&self) -> Option<(Self, Self)> {
+        let center = self.center();
+        if self.wide_width() >= self.wide_height() {
+            self.split_at_x(center.x)
+        } else {
+            self.split_at_y(center.y)
+        }
+    }
+
+    /// Returns an iterator over every point in the rect in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let rect = Rect::new(Point::new(1, 1), Point::new(3, 3));
+    /// let points: Vec<Point> = rect.points().collect();
+    ///
+    /// let expected: Vec<Point> = vec![(1, 1), (2, 1), (1, 2), (2, 2)].into_iter().map(Point::from).collect();
+    /// assert_eq!(points, expected);
+    /// ```
+    pub fn points(&self) -> RectPoints {
+        RectPoints {
+            rect: *self,
+            index: 0,
+            len: self.wide_width() * self.wide_height(),
+        }
+    }
+
+    /// Returns an iterator over the points on the edge of the rect, going
+    /// clockwise from the minimum corner when the y axis points downwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect};
+    ///
+    /// let rect = Rect::new(Point::new(0, 0), Point::new(3, 3));
+    /// let edge: Vec<Point> = rect.perimeter().collect();
+    ///
+    /// let expected: Vec<Point> = vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2), (0, 1)]
+    ///     .into_iter()
+    ///     .map(Point::from)
+    ///     .collect();
+    /// assert_eq!(edge, expected);
+    /// ```
+    pub fn perimeter(&self) -> Perimeter {
+        let (w, h) = (self.wide_width(), self.wide_height());
+        let len = if w == 0 || h == 0 {
+            0
+        } else if w == 1 || h == 1 {
+            w * h
+        } else {
+            2 * (w + h) - 4
+        };
+
+        Perimeter {
+            rect: *self,
+            index: 0,
+            len,
+        }
+    }
+}
+
+/// An iterator over every point in a [Rect] in row-major order.
+#[derive(Clone, Debug)]
+pub struct RectPoints {
+    rect: Rect,
+    index: u64,
+    len: u64,
+}
+
+impl Iterator for RectPoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let width = self.rect.wide_width();
+        let pos = Point::new(
+            offset(self.rect.min.x, (self.index % width) as i64),
+            offset(self.rect.min.y, (self.index / width) as i64),
+        );
+        self.index += 1;
+
+        Some(pos)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for RectPoints {}
+
+impl FusedIterator for RectPoints {}
+
+/// An iterator over the points on the edge of a [Rect].
+#[derive(Clone, Debug)]
+pub struct Perimeter {
+    rect: Rect,
+    index: u64,
+    len: u64,
+}
+
+impl Iterator for Perimeter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let (w, h) = (self.rect.wide_width(), self.rect.wide_height());
+        let (min, max) = (self.rect.min, self.rect.max);
+        let i = self.index;
+        self.index += 1;
+
+        // Walk along the top edge, down the right edge, back along the bottom
+        // edge and then up the left edge. A rect one point wide or tall is
+        // walked along its single line of points.
+        let pos = if i < w {
+            Point::new(offset(min.x, i as i64), min.y)
+        } else if i < w + h - 1 {
+            Point::new(max.x - 1, offset(min.y, (i - w + 1) as i64))
+        } else if i < 2 * w + h - 2 {
+            Point::new(offset(max.x - 1, -((i - (w + h - 2)) as i64)), max.y - 1)
+        } else {
+            Point::new(min.x, offset(max.y - 1, -((i - (2 * w + h - 3)) as i64)))
+        };
+
+        Some(pos)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Perimeter {}
+
+impl FusedIterator for Perimeter {}
+
+/// Returns the number of integers from min up to but excluding max, or 0 if
+/// max is not greater than min.
+const fn span(min: i32, max: i32) -> u64 {
+    if max > min {
+        (max as i64 - min as i64) as u64
+    } else {
+        0
+    }
+}
+
+/// Returns start moved by delta, where the result lies within the rect being
+/// iterated over but delta may not fit in an i32.
+const fn offset(start: i32, delta: i64) -> i32 {
+    (start as i64 + delta) as i32
+}
@@ -0,0 +1,65 @@
+//! Seeded pseudo-random numbers shared by the procedural generators.
+
+/// A small, fast pseudo-random number generator ([SplitMix64](https://prng.di.unimi.it/splitmix64.c)),
+/// used by [generate](crate::generate) and [noise](crate::noise) so that their
+/// output only depends on the seed.
+///
+/// It is not suitable for cryptographic use.
+///
+/// # Examples
+///
+/// ```
+/// use point::Rng;
+///
+/// let mut a = Rng::new(42);
+/// let mut b = Rng::new(42);
+///
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// assert!((3..7).contains(&a.range(3, 7)));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Returns a new generator with the given seed.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random u64.
+    #[inline]
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random f64 in the range [0, 1).
+    #[inline]
+    pub const fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a random i32 in the range [min, max).
+    ///
+    /// # Panics
+    ///
+    /// Panics if max is not greater than min.
+    pub const fn range(&mut self, min: i32, max: i32) -> i32 {
+        assert!(min < max, "range must not be empty");
+        let span = (max as i64 - min as i64) as u128;
+        let offset = (self.next_u64() as u128 * span) >> 64;
+        (min as i64 + offset as i64) as i32
+    }
+
+    /// Returns true with the given probability.
+    #[inline]
+    pub const fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+}
@@ -0,0 +1,208 @@
+//! Traits describing the numeric types a [Point](crate::Point) can hold.
+//!
+//! These traits are sealed, and are implemented for every primitive integer
+//! and floating point type.
+use std::{fmt, hash::Hash, ops};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive numeric type usable as the co-ordinates of a point.
+pub trait Scalar:
+    sealed::Sealed
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::AddAssign
+    + ops::SubAssign
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Returns the absolute difference between self and other, saturating at
+    /// the largest value of the type if it is too large to represent, such as
+    /// the difference between i32::MIN and i32::MAX.
+    fn abs_difference(self, other: Self) -> Self;
+
+    /// Returns the absolute difference between self and other as an f64.
+    /// Unlike converting the result of abs_difference, this never saturates.
+    fn abs_difference_f64(self, other: Self) -> f64;
+
+    /// Returns self + other, saturating at the bounds of the type for
+    /// integers.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Converts the value to an f64, rounding to the nearest representable
+    /// value if necessary.
+    fn to_f64(self) -> f64;
+}
+
+/// A scalar that can be negative.
+pub trait Signed: Scalar + ops::Neg<Output = Self> {}
+
+/// A primitive integer type.
+pub trait Integer: Scalar + Eq + Ord + Hash + ops::Rem<Output = Self> {
+    /// Converts the value to an i128. The conversion wraps for u128 values
+    /// greater than i128::MAX.
+    fn to_i128(self) -> i128;
+
+    /// Converts an i128 to this type, truncating values that are out of range.
+    fn from_i128(val: i128) -> Self;
+}
+
+/// A primitive floating point type.
+pub trait Float: Signed {
+    /// Returns the square root of the value.
+    fn sqrt(self) -> Self;
+
+    /// Returns the four quadrant arctangent of self (y) and other (x) in radians.
+    fn atan2(self, other: Self) -> Self;
+
+    /// Returns the largest integer less than or equal to the value.
+    fn floor(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to the value.
+    fn ceil(self) -> Self;
+
+    /// Returns the nearest integer to the value, rounding half-way cases away
+    /// from zero.
+    fn round(self) -> Self;
+
+    /// Returns the integer part of the value, rounding towards zero.
+    fn trunc(self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),* ; $zero:literal, $one:literal) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Scalar for $t {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+
+            #[inline]
+            fn abs_difference(self, other: Self) -> Self {
+                Self::try_from(self.abs_diff(other)).unwrap_or(Self::MAX)
+            }
+
+            #[inline]
+            fn abs_difference_f64(self, other: Self) -> f64 {
+                self.abs_diff(other) as f64
+            }
+
+            #[inline]
+            fn saturating_add(self, other: Self) -> Self {
+                self.saturating_add(other)
+            }
+
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
+    ($($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            #[inline]
+            fn abs_difference(self, other: Self) -> Self {
+                (self - other).abs()
+            }
+
+            #[inline]
+            fn abs_difference_f64(self, other: Self) -> f64 {
+                (self - other).abs() as f64
+            }
+
+            #[inline]
+            fn saturating_add(self, other: Self) -> Self {
+                self + other
+            }
+
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            #[inline]
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            #[inline]
+            fn from_i128(val: i128) -> Self {
+                val as Self
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {}
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {$(
+        impl Float for $t {
+            #[inline]
+            fn sqrt(self) -> Self {
+                self.sqrt()
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                self.atan2(other)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                self.floor()
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                self.ceil()
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                self.round()
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                self.trunc()
+            }
+        }
+    )*};
+}
+
+impl_scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize; 0, 1);
+impl_scalar!(f32, f64);
+impl_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+impl_float!(f32, f64);
@@ -0,0 +1,352 @@
+//! [Serde](https://serde.rs) support, enabled by the `serde` feature.
+//!
+//! A [Point] is serialized as a two element sequence, `[x, y]`. In
+//! human-readable formats, points can also be deserialized from a map with
+//! `x` and `y` keys. To serialize a point as a map instead, use the [as_map]
+//! module with serde's `with` attribute. A [Point3] is likewise serialized as
+//! `[x, y, z]`, and can be deserialized from a map with `x`, `y` and `z` keys
+//! in human-readable formats.
+//!
+//! [Rect](crate::Rect)s are serialized as a struct containing their `min` and `max`
+//! corners, and [Grid]s as a struct containing their `width`, `height` and
+//! row-major `cells`. Deserializing a grid fails if the number of cells does
+//! not match its dimensions. [Topology]s are serialized as a struct
+//! containing their `width`, `height` and `wrap`, and deserializing one fails
+//! if either dimension is not positive.
+//!
+//! # Examples
+//!
+//! ```
+//! use point::topology::{Topology, Wrap};
+//! use point::{Grid, Point, Point3, Rect};
+//!
+//! let p = Point::new(3, -4);
+//! let json = serde_json::to_string(&p).unwrap();
+//!
+//! assert_eq!(json, "[3,-4]");
+//! assert_eq!(serde_json::from_str::<Point>(&json).unwrap(), p);
+//! assert_eq!(serde_json::from_str::<Point>(r#"{"x":3,"y":-4}"#).unwrap(), p);
+//!
+//! let rect = Rect::new(Point::new(0, 1), Point::new(4, 5));
+//! let ron = ron::to_string(&rect).unwrap();
+//!
+//! assert_eq!(ron, "(min:(0,1),max:(4,5))");
+//! assert_eq!(ron::from_str::<Rect>(&ron).unwrap(), rect);
+//!
+//! let grid = Grid::from_fn(2, 2, |p| p.x + p.y);
+//! let json = serde_json::to_string(&grid).unwrap();
+//!
+//! assert_eq!(json, r#"{"width":2,"height":2,"cells":[0,1,1,2]}"#);
+//! assert_eq!(serde_json::from_str::<Grid<i32>>(&json).unwrap(), grid);
+//! assert!(serde_json::from_str::<Grid<i32>>(r#"{"width":2,"height":2,"cells":[0]}"#).is_err());
+//!
+//! let torus = Topology::new(8, 6, Wrap::Torus);
+//! let json = serde_json::to_string(&torus).unwrap();
+//!
+//! assert_eq!(json, r#"{"width":8,"height":6,"wrap":"Torus"}"#);
+//! assert_eq!(serde_json::from_str::<Topology>(&json).unwrap(), torus);
+//! assert!(serde_json::from_str::<Topology>(r#"{"width":0,"height":6,"wrap":"Torus"}"#).is_err());
+//!
+//! let p3 = Point3::new(1, 2, 3);
+//!
+//! assert_eq!(serde_json::to_string(&p3).unwrap(), "[1,2,3]");
+//! assert_eq!(serde_json::from_str::<Point3>(r#"{"z":3,"x":1,"y":2}"#).unwrap(), p3);
+//! ```
+//!
+//! Formats that are not self-describing, such as postcard and bincode, are
+//! also supported:
+//!
+//! ```
+//! use point::{Grid, Point, Point3, Rect};
+//!
+//! let p = Point::new(i32::MIN, 7);
+//! let bytes = postcard::to_allocvec(&p).unwrap();
+//! assert_eq!(postcard::from_bytes::<Point>(&bytes).unwrap(), p);
+//!
+//! let p3 = Point3::new(-1.5, 0.0, 2.25);
+//! let bytes = postcard::to_allocvec(&p3).unwrap();
+//! assert_eq!(postcard::from_bytes::<Point3<f64>>(&bytes).unwrap(), p3);
+//!
+//! let rect = Rect::new(Point::new(0, 1), Point::new(4, 5));
+//! let bytes = postcard::to_allocvec(&rect).unwrap();
+//! assert_eq!(postcard::from_bytes::<Rect>(&bytes).unwrap(), rect);
+//!
+//! let grid = Grid::from_fn(3, 2, |p| p);
+//! let bytes = postcard::to_allocvec(&grid).unwrap();
+//! assert_eq!(postcard::from_bytes::<Grid<Point>>(&bytes).unwrap(), grid);
+//! ```
+use crate::{
+    Grid, Point, Point3,
+    topology::{Topology, Wrap},
+};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, SerializeTuple},
+};
+use std::{fmt, marker::PhantomData};
+
+impl<T: Serialize> Serialize for Point<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tup = serializer.serialize_tuple(2)?;
+        tup.serialize_element(&self.x)?;
+        tup.serialize_element(&self.y)?;
+        tup.end()
+    }
+}
+
+/// The names of the fields of a point when represented as a map.
+const FIELDS: &[&str] = &["x", "y"];
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    X,
+    Y,
+}
+
+/// Deserializes a point from either a sequence or a map.
+struct PointVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for PointVisitor<T> {
+    type Value = Point<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a point as [x, y] or {x, y}")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let x = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let y = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+        Ok(Point { x, y })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut x, mut y) = (None, None);
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::X if x.is_some() => return Err(de::Error::duplicate_field("x")),
+                Field::Y if y.is_some() => return Err(de::Error::duplicate_field("y")),
+                Field::X => x = Some(map.next_value()?),
+                Field::Y => y = Some(map.next_value()?),
+            }
+        }
+        Ok(Point {
+            x: x.ok_or_else(|| de::Error::missing_field("x"))?,
+            y: y.ok_or_else(|| de::Error::missing_field("y"))?,
+        })
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Point<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(PointVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_tuple(2, PointVisitor(PhantomData))
+        }
+    }
+}
+
+/// Serializes a point as a map with `x` and `y` keys, for use with serde's
+/// `with` attribute.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Spawn {
+///     #[serde(with = "point::serialize::as_map")]
+///     pos: Point,
+/// }
+///
+/// let spawn = Spawn { pos: Point::new(1, 2) };
+/// let json = serde_json::to_string(&spawn).unwrap();
+///
+/// assert_eq!(json, r#"{"pos":{"x":1,"y":2}}"#);
+/// assert_eq!(serde_json::from_str::<Spawn>(&json).unwrap(), spawn);
+/// ```
+pub mod as_map {
+    use super::{FIELDS, PointVisitor};
+    use crate::Point;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeStruct};
+    use std::marker::PhantomData;
+
+    /// Serializes the point as a map with `x` and `y` keys.
+    pub fn serialize<T, S>(point: &Point<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_struct("Point", 2)?;
+        map.serialize_field("x", &point.x)?;
+        map.serialize_field("y", &point.y)?;
+        map.end()
+    }
+
+    /// Deserializes a point from a map with `x` and `y` keys.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Point<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Point", FIELDS, PointVisitor(PhantomData))
+    }
+}
+
+impl<T: Serialize> Serialize for Point3<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tup = serializer.serialize_tuple(3)?;
+        tup.serialize_element(&self.x)?;
+        tup.serialize_element(&self.y)?;
+        tup.serialize_element(&self.z)?;
+        tup.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field3 {
+    X,
+    Y,
+    Z,
+}
+
+/// Deserializes a 3D point from either a sequence or a map.
+struct Point3Visitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for Point3Visitor<T> {
+    type Value = Point3<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a point as [x, y, z] or {x, y, z}")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let x = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let y = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let z = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(4, &self));
+        }
+        Ok(Point3 { x, y, z })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut x, mut y, mut z) = (None, None, None);
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field3::X if x.is_some() => return Err(de::Error::duplicate_field("x")),
+                Field3::Y if y.is_some() => return Err(de::Error::duplicate_field("y")),
+                Field3::Z if z.is_some() => return Err(de::Error::duplicate_field("z")),
+                Field3::X => x = Some(map.next_value()?),
+                Field3::Y => y = Some(map.next_value()?),
+                Field3::Z => z = Some(map.next_value()?),
+            }
+        }
+        Ok(Point3 {
+            x: x.ok_or_else(|| de::Error::missing_field("x"))?,
+            y: y.ok_or_else(|| de::Error::missing_field("y"))?,
+            z: z.ok_or_else(|| de::Error::missing_field("z"))?,
+        })
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Point3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Point3Visitor(PhantomData))
+        } else {
+            deserializer.deserialize_tuple(3, Point3Visitor(PhantomData))
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut grid = serializer.serialize_struct("Grid", 3)?;
+        grid.serialize_field("width", &self.width())?;
+        grid.serialize_field("height", &self.height())?;
+        grid.serialize_field("cells", self.as_slice())?;
+        grid.end()
+    }
+}
+
+/// The serialized form of a grid, before its dimensions are validated.
+#[derive(Deserialize)]
+#[serde(rename = "Grid")]
+struct RawGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawGrid {
+            width,
+            height,
+            cells,
+        } = RawGrid::deserialize(deserializer)?;
+        let len = cells.len();
+
+        Grid::from_vec(width, height, cells).ok_or_else(|| {
+            de::Error::invalid_length(len, &format!("{} cells", width * height).as_str())
+        })
+    }
+}
+
+impl Serialize for Topology {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut topology = serializer.serialize_struct("Topology", 3)?;
+        topology.serialize_field("width", &self.width())?;
+        topology.serialize_field("height", &self.height())?;
+        topology.serialize_field("wrap", &self.wrap())?;
+        topology.end()
+    }
+}
+
+/// The serialized form of a topology, before its dimensions are validated.
+#[derive(Deserialize)]
+#[serde(rename = "Topology")]
+struct RawTopology {
+    width: i32,
+    height: i32,
+    wrap: Wrap,
+}
+
+impl<'de> Deserialize<'de> for Topology {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawTopology {
+            width,
+            height,
+            wrap,
+        } = RawTopology::deserialize(deserializer)?;
+
+        for (name, len) in [("width", width), ("height", height)] {
+            if len <= 0 {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Signed(len.into()),
+                    &format!("a positive {name}").as_str(),
+                ));
+            }
+        }
+        Ok(Topology::new(width, height, wrap))
+    }
+}
@@ -0,0 +1,492 @@
+//! Iterators over the points of circles, ellipses and arcs.
+use crate::{Point, metric::Metric};
+use std::{f64::consts::TAU, iter::FusedIterator};
+
+/// Selects which points are considered to be inside a circle or ellipse.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiskShape {
+    /// Points whose Euclidean distance from the centre is at most the radius;
+    /// for a circle, those where `p.dist_squared(centre) <= r * r`. This gives
+    /// the mathematically exact shape, but leaves a single point sticking out
+    /// at each end of the axes.
+    Euclidean,
+    /// Points whose Euclidean distance from the centre is at most the radius
+    /// plus a half; for a circle, those where
+    /// `p.dist_squared(centre) <= r * r + r`. This gives rounder looking
+    /// shapes on a grid, matching the midpoint circle algorithm.
+    #[default]
+    Nice,
+}
+
+/// The dimensions of an axis-aligned ellipse, measured in half units so that
+/// both shapes can be handled with integer arithmetic.
+#[derive(Clone, Copy, Debug)]
+struct Ellipse {
+    a: u128,
+    b: u128,
+}
+
+impl Ellipse {
+    fn new(rx: u32, ry: u32, shape: DiskShape) -> Self {
+        assert!(
+            rx <= 1 << 24 && ry <= 1 << 24,
+            "radius of ellipse is too large"
+        );
+        let extra = match shape {
+            DiskShape::Euclidean => 0,
+            DiskShape::Nice => 1,
+        };
+
+        Self {
+            a: 2 * rx as u128 + extra,
+            b: 2 * ry as u128 + extra,
+        }
+    }
+
+    /// Returns the greatest x offset of a point inside the ellipse with the
+    /// given y offset, or -1 if there are no such points.
+    fn half_width(&self, y: i32) -> i32 {
+        let y = y.unsigned_abs() as u128;
+        if 2 * y > self.b {
+            return -1;
+        }
+        if self.b == 0 {
+            return (self.a / 2) as i32;
+        }
+
+        // A point is inside if (2x)^2 b^2 + (2y)^2 a^2 <= a^2 b^2.
+        let rhs = self.a * self.a * (self.b * self.b - 4 * y * y);
+        let max = (rhs / (4 * self.b * self.b)).isqrt();
+        max.min(self.a / 2) as i32
+    }
+
+    /// Returns the half width of each row of the ellipse, from the centre
+    /// outwards.
+    fn widths(&self) -> Vec<i32> {
+        (0..=(self.b / 2) as i32)
+            .map(|y| self.half_width(y))
+            .collect()
+    }
+}
+
+/// Returns the half width of each row of the points within radius of the
+/// origin under the given metric, from the centre outwards.
+///
+/// # Panics
+///
+/// Panics if radius is greater than 2^24.
+fn metric_widths<M: Metric>(radius: u32, metric: &M) -> Vec<i32> {
+    assert!(radius <= 1 << 24, "radius is too large");
+    let radius = radius as i32;
+    let mut widths = Vec::new();
+
+    // Every metric is at least the Chebyshev distance, so no point further
+    // than radius along either axis is inside.
+    for y in 0..=radius {
+        if !metric.within(Point::ORIGIN, Point::new(0, y), radius as u32) {
+            break;
+        }
+        // Points inside the row form a run from the centre outwards, so the
+        // end of the run can be found with a binary search.
+        let (mut inside, mut outside) = (0, radius + 1);
+        while outside - inside > 1 {
+            let mid = (inside + outside) / 2;
+            if metric.within(Point::ORIGIN, Point::new(mid, y), radius as u32) {
+                inside = mid;
+            } else {
+                outside = mid;
+            }
+        }
+        widths.push(inside);
+    }
+
+    widths
+}
+
+/// Returns the outline of a shape within the quadrant of non-negative
+/// offsets, going anti-clockwise from the x axis to the y axis, given the
+/// half width of each of its rows from the centre outwards.
+fn quadrant_outline(widths: &[i32]) -> Vec<Point> {
+    let mut quadrant = Vec::new();
+
+    for (y, &width) in widths.iter().enumerate() {
+        // Points in this row are on the outline if they are at the end of
+        // the row, or if the point above them is outside.
+        let above = widths.get(y + 1).copied().unwrap_or(-1);
+        let lowest = (above + 1).min(width);
+        quadrant.extend((lowest..=width).rev().map(|x| Point::new(x, y as i32)));
+    }
+
+    quadrant
+}
+
+/// An iterator over the outline of a circle or an axis-aligned ellipse.
+///
+/// The outline consists of every point inside the shape that is orthogonally
+/// adjacent to a point outside of it. Points are yielded anti-clockwise, when
+/// the y axis points upwards, starting from the point furthest along the
+/// positive x axis. Each point is yielded exactly once, so consecutive points
+/// of a circle are always adjacent, possibly diagonally, but a very narrow
+/// ellipse may jump between the sides of its ends.
+///
+/// # Examples
+///
+/// ```
+/// use point::{DiskShape, Point};
+///
+/// let ring: Vec<Point> = Point::plot_circle(Point::ORIGIN, 1, DiskShape::Nice).collect();
+///
+/// let expected: Vec<Point> = vec![(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// assert_eq!(ring, expected);
+///
+/// let diamond: Vec<Point> = Point::plot_circle(Point::ORIGIN, 1, DiskShape::Euclidean).collect();
+///
+/// let expected: Vec<Point> = vec![(1, 0), (0, 1), (-1, 0), (0, -1)].into_iter().map(Point::from).collect();
+/// assert_eq!(diamond, expected);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CircleIter {
+    centre: Point,
+    /// The outline within the quadrant of non-negative offsets, going
+    /// anti-clockwise from the x axis to the y axis.
+    quadrant: Vec<Point>,
+    phase: usize,
+    index: usize,
+}
+
+impl CircleIter {
+    /// Returns an iterator over the outline of the circle with the given
+    /// centre and radius.
+    pub fn new(centre: Point, radius: u32, shape: DiskShape) -> Self {
+        Self::ellipse(centre, radius, radius, shape)
+    }
+
+    /// Returns an iterator over the outline of the axis-aligned ellipse with
+    /// the given centre and radii along the x and y axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is greater than 2^24.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{CircleIter, DiskShape, Point};
+    ///
+    /// let ellipse: Vec<Point> = CircleIter::ellipse(Point::ORIGIN, 3, 1, DiskShape::Nice).collect();
+    ///
+    /// assert!(ellipse.contains(&Point::new(3, 0)));
+    /// assert!(ellipse.contains(&Point::new(-2, 1)));
+    /// assert!(!ellipse.contains(&Point::new(-2, 0)));
+    /// assert_eq!(ellipse.len(), 12);
+    /// ```
+    pub fn ellipse(centre: Point, rx: u32, ry: u32, shape: DiskShape) -> Self {
+        let ellipse = Ellipse::new(rx, ry, shape);
+        Self::from_widths(centre, &ellipse.widths())
+    }
+
+    /// Returns an iterator over the outline of the points within radius of
+    /// centre under the given metric.
+    ///
+    /// # Panics
+    ///
+    /// Panics if radius is greater than 2^24.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{CircleIter, Point};
+    /// use point::metric::{Chebyshev, Manhattan};
+    ///
+    /// let square: Vec<Point> = CircleIter::with_metric(Point::ORIGIN, 2, Chebyshev).collect();
+    /// let diamond: Vec<Point> = CircleIter::with_metric(Point::ORIGIN, 2, Manhattan).collect();
+    ///
+    /// assert_eq!(square.len(), 16);
+    /// assert!(square.iter().all(|p| p.x.abs() == 2 || p.y.abs() == 2));
+    /// assert_eq!(diamond.len(), 8);
+    /// assert!(diamond.iter().all(|p| p.x.abs() + p.y.abs() >= 1));
+    /// ```
+    pub fn with_metric<M: Metric>(centre: Point, radius: u32, metric: M) -> Self {
+        Self::from_widths(centre, &metric_widths(radius, &metric))
+    }
+
+    fn from_widths(centre: Point, widths: &[i32]) -> Self {
+        Self {
+            centre,
+            quadrant: quadrant_outline(widths),
+            phase: 0,
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for CircleIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.quadrant.len();
+        while self.phase < 4 {
+            if self.index >= len {
+                self.phase += 1;
+                self.index = 0;
+                continue;
+            }
+            let i = self.index;
+            self.index += 1;
+
+            // Each quadrant is a reflection of the first, traversed forwards
+            // or backwards to keep going anti-clockwise. Points on the axes
+            // are assigned to the quadrant that starts at them. The centre is
+            // only on the outline if the shape is a line or a single point.
+            let (p, keep) = match self.phase {
+                0 => {
+                    let p = self.quadrant[i];
+                    (p, p.x > 0 || p == Point::ORIGIN)
+                }
+                1 => {
+                    let p = self.quadrant[len - 1 - i];
+                    (Point::new(-p.x, p.y), p.y > 0)
+                }
+                2 => {
+                    let p = self.quadrant[i];
+                    (Point::new(-p.x, -p.y), p.x > 0)
+                }
+                _ => {
+                    let p = self.quadrant[len - 1 - i];
+                    (Point::new(p.x, -p.y), p.y > 0)
+                }
+            };
+            if keep {
+                return Some(self.centre + p);
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for CircleIter {}
+
+/// An iterator over every point inside a circle or an axis-aligned ellipse,
+/// in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// use point::{DiskShape, Point};
+///
+/// let centre = Point::new(5, 5);
+/// let disk: Vec<Point> = Point::plot_disk(centre, 4, DiskShape::Euclidean).collect();
+///
+/// assert!(disk.iter().all(|p| p.dist_squared(centre) <= 16));
+/// assert_eq!(disk.len(), (-4..=4).flat_map(|x| (-4..=4).map(move |y| x * x + y * y)).filter(|&d| d <= 16).count());
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiskIter {
+    centre: Point,
+    /// The half width of each row, from the centre outwards.
+    widths: Vec<i32>,
+    y: i32,
+    x: i32,
+    width: i32,
+}
+
+impl DiskIter {
+    /// Returns an iterator over the points inside the circle with the given
+    /// centre and radius.
+    pub fn new(centre: Point, radius: u32, shape: DiskShape) -> Self {
+        Self::ellipse(centre, radius, radius, shape)
+    }
+
+    /// Returns an iterator over the points inside the axis-aligned ellipse
+    /// with the given centre and radii along the x and y axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is greater than 2^24.
+    pub fn ellipse(centre: Point, rx: u32, ry: u32, shape: DiskShape) -> Self {
+        let ellipse = Ellipse::new(rx, ry, shape);
+        Self::from_widths(centre, ellipse.widths())
+    }
+
+    /// Returns an iterator over the points within radius of centre under the
+    /// given metric.
+    ///
+    /// # Panics
+    ///
+    /// Panics if radius is greater than 2^24.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{DiskIter, Point};
+    /// use point::metric::{Chebyshev, Manhattan, Metric, Octile};
+    ///
+    /// assert_eq!(DiskIter::with_metric(Point::ORIGIN, 2, Chebyshev).count(), 25);
+    /// assert_eq!(DiskIter::with_metric(Point::ORIGIN, 2, Manhattan).count(), 13);
+    ///
+    /// let centre = Point::new(4, 4);
+    /// let octagon: Vec<Point> = DiskIter::with_metric(centre, 3, Octile).collect();
+    /// assert!(octagon.iter().all(|&p| Octile.within(centre, p, 3)));
+    /// assert_eq!(octagon.len(), 29);
+    /// ```
+    pub fn with_metric<M: Metric>(centre: Point, radius: u32, metric: M) -> Self {
+        Self::from_widths(centre, metric_widths(radius, &metric))
+    }
+
+    fn from_widths(centre: Point, widths: Vec<i32>) -> Self {
+        let y = 1 - widths.len() as i32;
+        let width = widths.last().copied().unwrap_or(-1);
+
+        Self {
+            centre,
+            widths,
+            y,
+            x: -width,
+            width,
+        }
+    }
+}
+
+impl Iterator for DiskIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x > self.width {
+            if self.y >= self.widths.len() as i32 - 1 {
+                return None;
+            }
+            self.y += 1;
+            self.width = self.widths[self.y.unsigned_abs() as usize];
+            self.x = -self.width;
+        }
+        let p = self.centre + Point::new(self.x, self.y);
+        self.x += 1;
+
+        Some(p)
+    }
+}
+
+impl FusedIterator for DiskIter {}
+
+/// An iterator over the points of a circle's outline between two angles.
+///
+/// Angles are in radians, measured anti-clockwise from the positive x axis
+/// as with [Vec2::angle](crate::Vec2). The arc runs anti-clockwise from the
+/// start angle to the end angle, and its points are yielded in that order.
+/// If the end angle is at least a full turn after the start angle, the whole
+/// circle is included.
+///
+/// # Examples
+///
+/// ```
+/// use point::{ArcIter, DiskShape, Point};
+/// use std::f64::consts::{FRAC_PI_2, PI};
+///
+/// // The upper half of a circle, from the positive to the negative x axis.
+/// let arc: Vec<Point> = ArcIter::new(Point::ORIGIN, 1, 0.0, PI, DiskShape::Nice).collect();
+///
+/// let expected: Vec<Point> = vec![(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)].into_iter().map(Point::from).collect();
+/// assert_eq!(arc, expected);
+///
+/// // An arc crossing the positive x axis still starts at its start angle.
+/// let arc: Vec<Point> = ArcIter::new(Point::ORIGIN, 1, -FRAC_PI_2, FRAC_PI_2, DiskShape::Nice).collect();
+///
+/// let expected: Vec<Point> = vec![(0, -1), (1, -1), (1, 0), (1, 1), (0, 1)].into_iter().map(Point::from).collect();
+/// assert_eq!(arc, expected);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ArcIter {
+    centre: Point,
+    outline: CircleIter,
+    /// The outline is traversed twice, as the arc may start part of the way
+    /// round it and wrap back past the positive x axis.
+    second_pass: CircleIter,
+    on_second_pass: bool,
+    start: f64,
+    span: f64,
+}
+
+impl ArcIter {
+    /// Returns an iterator over the points of the outline of the circle with
+    /// the given centre and radius, between the start and end angles.
+    pub fn new(centre: Point, radius: u32, start: f64, end: f64, shape: DiskShape) -> Self {
+        let outline = CircleIter::new(centre, radius, shape);
+        let span = if end - start >= TAU {
+            TAU
+        } else {
+            (end - start).rem_euclid(TAU)
+        };
+
+        Self {
+            centre,
+            second_pass: outline.clone(),
+            outline,
+            on_second_pass: false,
+            start: start.rem_euclid(TAU),
+            span,
+        }
+    }
+
+    /// Returns the angle of p anti-clockwise from the positive x axis, in
+    /// the range [0, 2π).
+    fn angle(&self, p: Point) -> f64 {
+        let disp = p - self.centre;
+        (disp.y as f64).atan2(disp.x as f64).rem_euclid(TAU)
+    }
+
+    /// Returns true if p lies on the arc.
+    fn on_arc(&self, p: Point) -> bool {
+        (self.angle(p) - self.start).rem_euclid(TAU) <= self.span
+    }
+}
+
+impl Iterator for ArcIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // A circle of a single point lies on every arc.
+        if self.outline.quadrant == [Point::ORIGIN] {
+            return self.outline.next();
+        }
+
+        // As the outline starts at an angle of 0, the first pass yields
+        // points at or after the start angle, and the second pass yields
+        // those before it.
+        if !self.on_second_pass {
+            while let Some(p) = self.outline.next() {
+                if self.angle(p) >= self.start && self.on_arc(p) {
+                    return Some(p);
+                }
+            }
+            self.on_second_pass = true;
+        }
+        while let Some(p) = self.second_pass.next() {
+            if self.angle(p) < self.start && self.on_arc(p) {
+                return Some(p);
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for ArcIter {}
+
+impl Point {
+    /// Returns an iterator over the outline of the circle with the given
+    /// centre and radius. See [CircleIter] for details.
+    pub fn plot_circle(centre: Self, radius: u32, shape: DiskShape) -> CircleIter {
+        CircleIter::new(centre, radius, shape)
+    }
+
+    /// Returns an iterator over every point inside the circle with the given
+    /// centre and radius. See [DiskIter] for details.
+    pub fn plot_disk(centre: Self, radius: u32, shape: DiskShape) -> DiskIter {
+        DiskIter::new(centre, radius, shape)
+    }
+}
@@ -0,0 +1,693 @@
+//! Spatial indexes for finding values stored at [Point]s.
+use crate::{Point, Rect, metric::Metric};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+/// Returns the inclusive corners of the square containing every point within
+/// radius of centre.
+fn radius_corners(centre: Point, radius: u32) -> (Point, Point) {
+    let radius = radius.min(i32::MAX as u32) as i32;
+    let offset = Point::new(radius, radius);
+    (centre.saturating_sub(offset), centre.saturating_add(offset))
+}
+
+/// Returns a key that sorts distances in the same order as the distances
+/// themselves. The bits of a non-negative f64 have the same order as its value.
+fn dist_key<M: Metric>(metric: &M, a: Point, b: Point) -> u64 {
+    metric.dist(a, b).to_bits()
+}
+
+/// Sorts the candidates found by a nearest neighbour search and keeps the
+/// nearest k.
+fn nearest_k<T>(mut found: Vec<(u64, Point, &T)>, k: usize) -> Vec<(Point, &T)> {
+    found.sort_unstable_by_key(|&(dist, p, _)| (dist, p.y, p.x));
+    found.into_iter().take(k).map(|(_, p, v)| (p, v)).collect()
+}
+
+/// An unbounded index that sorts values into square buckets of a fixed size.
+///
+/// Queries only need to look at the buckets overlapping the queried area, so
+/// they are fastest when the cell size is close to the typical query radius.
+/// Many values may be stored at the same point.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, SpatialHash};
+/// use point::metric::{Euclidean, Manhattan};
+///
+/// let mut entities = SpatialHash::new(8);
+/// entities.insert(Point::new(2, 3), "goblin");
+/// entities.insert(Point::new(40, -7), "dragon");
+/// entities.insert(Point::new(5, 5), "rat");
+///
+/// let near = entities.query_radius(Point::new(3, 3), 3, Euclidean);
+/// assert_eq!(near.len(), 2);
+///
+/// entities.relocate(Point::new(40, -7), Point::new(4, 4), &"dragon");
+/// assert_eq!(entities.nearest(Point::new(4, 3), Manhattan), Some((Point::new(4, 4), &"dragon")));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialHash<T> {
+    cell_size: i32,
+    cells: HashMap<Point, Vec<(Point, T)>>,
+    len: usize,
+}
+
+impl<T> SpatialHash<T> {
+    /// Returns a new, empty spatial hash whose buckets are cell_size points
+    /// wide and high.
+    ///
+    /// # Panics
+    ///
+    /// Panics if cell_size is 0 or greater than i32::MAX.
+    pub fn new(cell_size: u32) -> Self {
+        assert!(
+            cell_size > 0 && cell_size <= i32::MAX as u32,
+            "cell size must be positive and fit in an i32"
+        );
+
+        Self {
+            cell_size: cell_size as i32,
+            cells: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the width and height of each bucket.
+    #[inline]
+    pub fn cell_size(&self) -> u32 {
+        self.cell_size as u32
+    }
+
+    /// Returns the number of values stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no values are stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every value.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.len = 0;
+    }
+
+    /// Returns the bucket containing pos.
+    fn cell(&self, pos: Point) -> Point {
+        Point::new(
+            pos.x.div_euclid(self.cell_size),
+            pos.y.div_euclid(self.cell_size),
+        )
+    }
+
+    /// Stores value at pos.
+    pub fn insert(&mut self, pos: Point, value: T) {
+        let cell = self.cell(pos);
+        self.cells.entry(cell).or_default().push((pos, value));
+        self.len += 1;
+    }
+
+    /// Removes a value equal to the given one from pos and returns it, or
+    /// returns None if there is no such value.
+    pub fn remove(&mut self, pos: Point, value: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let cell = self.cell(pos);
+        let bucket = self.cells.get_mut(&cell)?;
+        let index = bucket.iter().position(|(p, v)| *p == pos && v == value)?;
+        let (_, removed) = bucket.swap_remove(index);
+        if bucket.is_empty() {
+            self.cells.remove(&cell);
+        }
+        self.len -= 1;
+
+        Some(removed)
+    }
+
+    /// Moves a value equal to the given one from `from` to `to`. Returns
+    /// false if there is no such value at `from`.
+    pub fn relocate(&mut self, from: Point, to: Point, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        if self.cell(from) == self.cell(to) {
+            let Some(bucket) = self.cells.get_mut(&self.cell(from)) else {
+                return false;
+            };
+            match bucket.iter_mut().find(|(p, v)| *p == from && v == value) {
+                Some((p, _)) => {
+                    *p = to;
+                    true
+                }
+                None => false,
+            }
+        } else if let Some(removed) = self.remove(from, value) {
+            self.insert(to, removed);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns an iterator over every stored value and its position, in no
+    /// particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.values().flatten().map(|(p, v)| (*p, v))
+    }
+
+    /// Returns an iterator over the values in every bucket that may contain
+    /// points between min and max inclusive.
+    fn candidates(&self, min: Point, max: Point) -> Box<dyn Iterator<Item = (Point, &T)> + '_> {
+        let (min, max) = (self.cell(min), self.cell(max));
+        let count = (max.x as i64 - min.x as i64 + 1) * (max.y as i64 - min.y as i64 + 1);
+
+        // Looking up every bucket in a large area is slower than scanning
+        // the buckets that actually exist.
+        if count > self.cells.len() as i64 {
+            Box::new(self.iter())
+        } else {
+            Box::new(
+                (min.y..=max.y)
+                    .flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+                    .filter_map(|cell| self.cells.get(&cell))
+                    .flatten()
+                    .map(|(p, v)| (*p, v)),
+            )
+        }
+    }
+
+    /// Returns every value whose position is inside rect, in no particular
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect, SpatialHash};
+    ///
+    /// let mut hash = SpatialHash::new(4);
+    /// hash.insert(Point::new(1, 1), 'a');
+    /// hash.insert(Point::new(6, 2), 'b');
+    ///
+    /// let found = hash.query_rect(&Rect::new(Point::new(0, 0), Point::new(6, 6)));
+    /// assert_eq!(found, vec![(Point::new(1, 1), &'a')]);
+    /// ```
+    pub fn query_rect(&self, rect: &Rect) -> Vec<(Point, &T)> {
+        if rect.is_empty() {
+            return Vec::new();
+        }
+        self.candidates(rect.min, rect.max - Point::new(1, 1))
+            .filter(|(p, _)| rect.contains(*p))
+            .collect()
+    }
+
+    /// Returns every value whose position is within radius of centre under
+    /// the given metric, in no particular order.
+    pub fn query_radius<M: Metric>(
+        &self,
+        centre: Point,
+        radius: u32,
+        metric: M,
+    ) -> Vec<(Point, &T)> {
+        let (min, max) = radius_corners(centre, radius);
+        self.candidates(min, max)
+            .filter(|(p, _)| metric.within(centre, *p, radius))
+            .collect()
+    }
+
+    /// Returns the value nearest to pos, or None if the hash is empty. Ties
+    /// are broken in favour of the lowest position in row-major order.
+    pub fn nearest<M: Metric>(&self, pos: Point, metric: M) -> Option<(Point, &T)> {
+        self.k_nearest(pos, 1, metric).pop()
+    }
+
+    /// Returns the k values nearest to pos, nearest first. Returns fewer than
+    /// k values if fewer are stored. Ties are broken in favour of the lowest
+    /// position in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, SpatialHash};
+    /// use point::metric::Euclidean;
+    ///
+    /// let mut hash = SpatialHash::new(2);
+    /// for x in 0..10 {
+    ///     hash.insert(Point::new(x, 0), x);
+    /// }
+    ///
+    /// let nearest: Vec<i32> = hash.k_nearest(Point::new(6, 1), 3, Euclidean).into_iter().map(|(_, &v)| v).collect();
+    /// assert_eq!(nearest, vec![6, 5, 7]);
+    /// ```
+    pub fn k_nearest<M: Metric>(&self, pos: Point, k: usize, metric: M) -> Vec<(Point, &T)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut found = Vec::new();
+        let centre = self.cell(pos);
+        let mut seen = 0;
+
+        // Search rings of buckets around pos, until every unsearched bucket is
+        // further away than the kth nearest value found so far.
+        for ring in 0i64.. {
+            if seen == self.len {
+                break;
+            }
+            if 8 * ring > self.cells.len() as i64 {
+                // The ring contains more buckets than exist, so scan them all.
+                found.extend(
+                    self.cells
+                        .iter()
+                        .filter(|(cell, _)| {
+                            let dx = (cell.x as i64 - centre.x as i64).abs();
+                            let dy = (cell.y as i64 - centre.y as i64).abs();
+                            dx.max(dy) >= ring
+                        })
+                        .flat_map(|(_, bucket)| bucket)
+                        .map(|(p, v)| (dist_key(&metric, pos, *p), *p, v)),
+                );
+                break;
+            }
+
+            let cells = (-ring..=ring).flat_map(|i| {
+                let edges = [(i, -ring), (i, ring), (-ring, i), (ring, i)];
+                // The corners are covered by the rows, and ring 0 is one cell.
+                let count = if ring == 0 {
+                    1
+                } else if i.abs() == ring {
+                    2
+                } else {
+                    4
+                };
+                edges.into_iter().take(count)
+            });
+            for (dx, dy) in cells {
+                let (Ok(x), Ok(y)) = (
+                    i32::try_from(centre.x as i64 + dx),
+                    i32::try_from(centre.y as i64 + dy),
+                ) else {
+                    continue;
+                };
+                if let Some(bucket) = self.cells.get(&Point::new(x, y)) {
+                    seen += bucket.len();
+                    found.extend(
+                        bucket
+                            .iter()
+                            .map(|(p, v)| (dist_key(&metric, pos, *p), *p, v)),
+                    );
+                }
+            }
+
+            // Points in later rings differ from pos by more than this along
+            // at least one axis, so are at least this far away.
+            let bound = (ring as f64 * self.cell_size as f64).to_bits();
+            if found.len() >= k {
+                found.select_nth_unstable_by_key(k - 1, |&(dist, p, _)| (dist, p.y, p.x));
+                if found[k - 1].0 <= bound {
+                    break;
+                }
+            }
+        }
+
+        nearest_k(found, k)
+    }
+}
+
+/// The maximum number of values stored in a leaf before it is split.
+const LEAF_CAPACITY: usize = 8;
+
+#[derive(Clone, Debug)]
+enum Node<T> {
+    Leaf(Vec<(Point, T)>),
+    /// The four quarters of the node's bounds, as given by [quarters].
+    Branch(Box<[(Rect, Node<T>); 4]>),
+}
+
+/// Splits a rect into four quarters. Some quarters will be empty if the rect
+/// is one point wide or high.
+fn quarters(rect: Rect) -> [Rect; 4] {
+    let (min, max) = (rect.min, rect.max);
+    let mid = Point::new(
+        (min.x as i64 + (max.x as i64 - min.x as i64) / 2) as i32,
+        (min.y as i64 + (max.y as i64 - min.y as i64) / 2) as i32,
+    );
+
+    [
+        Rect::new(min, mid),
+        Rect::new(Point::new(mid.x, min.y), Point::new(max.x, mid.y)),
+        Rect::new(Point::new(min.x, mid.y), Point::new(mid.x, max.y)),
+        Rect::new(mid, max),
+    ]
+}
+
+impl<T> Node<T> {
+    fn insert(&mut self, bounds: Rect, pos: Point, value: T) {
+        match self {
+            Self::Leaf(items) => {
+                items.push((pos, value));
+                if items.len() > LEAF_CAPACITY
+                    && (bounds.wide_width() > 1 || bounds.wide_height() > 1)
+                {
+                    let mut children = quarters(bounds).map(|rect| (rect, Self::Leaf(Vec::new())));
+                    for (p, v) in items.drain(..) {
+                        let (rect, child) = children
+                            .iter_mut()
+                            .find(|(rect, _)| rect.contains(p))
+                            .expect("quarters cover the whole rect");
+                        child.insert(*rect, p, v);
+                    }
+                    *self = Self::Branch(Box::new(children));
+                }
+            }
+            Self::Branch(children) => {
+                let (rect, child) = children
+                    .iter_mut()
+                    .find(|(rect, _)| rect.contains(pos))
+                    .expect("quarters cover the whole rect");
+                child.insert(*rect, pos, value);
+            }
+        }
+    }
+
+    fn remove(&mut self, pos: Point, value: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        match self {
+            Self::Leaf(items) => {
+                let index = items.iter().position(|(p, v)| *p == pos && v == value)?;
+                Some(items.swap_remove(index).1)
+            }
+            Self::Branch(children) => {
+                let (_, child) = children.iter_mut().find(|(rect, _)| rect.contains(pos))?;
+                let removed = child.remove(pos, value)?;
+
+                // Merge the children back into a leaf once they are small
+                // enough to fit in one.
+                let total = children
+                    .iter()
+                    .try_fold(0, |total, (_, child)| match child {
+                        Self::Leaf(items) => Some(total + items.len()),
+                        Self::Branch(_) => None,
+                    });
+                if total.is_some_and(|total| total <= LEAF_CAPACITY) {
+                    let mut items = Vec::new();
+                    for (_, child) in children.iter_mut() {
+                        if let Self::Leaf(child_items) = child {
+                            items.append(child_items);
+                        }
+                    }
+                    *self = Self::Leaf(items);
+                }
+
+                Some(removed)
+            }
+        }
+    }
+
+    /// Calls f with every value in a node whose bounds satisfy the filter.
+    fn visit<'a, R, F>(&'a self, bounds: Rect, filter: &R, f: &mut F)
+    where
+        R: Fn(&Rect) -> bool,
+        F: FnMut(Point, &'a T),
+    {
+        if !filter(&bounds) {
+            return;
+        }
+        match self {
+            Self::Leaf(items) => items.iter().for_each(|(p, v)| f(*p, v)),
+            Self::Branch(children) => {
+                for (rect, child) in children.iter() {
+                    child.visit(*rect, filter, f);
+                }
+            }
+        }
+    }
+}
+
+/// A bounded index that recursively splits its area into quarters, so that
+/// each region holds only a few values.
+///
+/// Unlike a [SpatialHash], a quad tree adapts to how densely packed its values
+/// are, but can only store values within the bounds given when it is created.
+/// Many values may be stored at the same point.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, QuadTree, Rect};
+/// use point::metric::Euclidean;
+///
+/// let mut tree = QuadTree::new(Rect::new(Point::new(0, 0), Point::new(100, 100)));
+/// for i in 0..50 {
+///     tree.insert(Point::new(i * 2, i), i).unwrap();
+/// }
+///
+/// assert_eq!(tree.len(), 50);
+/// assert_eq!(tree.nearest(Point::new(31, 14), Euclidean), Some((Point::new(30, 15), &15)));
+/// assert!(tree.insert(Point::new(100, 0), 50).is_err());
+///
+/// // The bounds may cover almost the whole range of i32.
+/// let mut tree = QuadTree::new(Rect::new(Point::new(i32::MIN, i32::MIN), Point::new(i32::MAX, i32::MAX)));
+/// for i in 0..50 {
+///     tree.insert(Point::new(i32::MIN + i, i32::MAX - 1 - i), i).unwrap();
+/// }
+///
+/// assert_eq!(tree.len(), 50);
+/// assert_eq!(tree.nearest(Point::new(i32::MIN, i32::MAX - 1), Euclidean), Some((Point::new(i32::MIN, i32::MAX - 1), &0)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuadTree<T> {
+    bounds: Rect,
+    root: Node<T>,
+    len: usize,
+}
+
+impl<T> QuadTree<T> {
+    /// Returns a new, empty quad tree covering the given bounds.
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            root: Node::Leaf(Vec::new()),
+            len: 0,
+        }
+    }
+
+    /// Returns the rect in which values may be stored.
+    #[inline]
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Returns the number of values stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no values are stored.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every value.
+    pub fn clear(&mut self) {
+        self.root = Node::Leaf(Vec::new());
+        self.len = 0;
+    }
+
+    /// Stores value at pos. Returns the value as the error if pos is outside
+    /// of the tree's bounds.
+    pub fn insert(&mut self, pos: Point, value: T) -> Result<(), T> {
+        if !self.bounds.contains(pos) {
+            return Err(value);
+        }
+        self.root.insert(self.bounds, pos, value);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes a value equal to the given one from pos and returns it, or
+    /// returns None if there is no such value.
+    pub fn remove(&mut self, pos: Point, value: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        if !self.bounds.contains(pos) {
+            return None;
+        }
+        let removed = self.root.remove(pos, value)?;
+        self.len -= 1;
+
+        Some(removed)
+    }
+
+    /// Moves a value equal to the given one from `from` to `to`. Returns
+    /// false if there is no such value at `from`, or if `to` is outside of the
+    /// tree's bounds, in which case the value is not moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, QuadTree, Rect};
+    ///
+    /// let mut tree = QuadTree::new(Rect::new(Point::new(0, 0), Point::new(8, 8)));
+    /// tree.insert(Point::new(1, 1), "player").unwrap();
+    ///
+    /// assert!(tree.relocate(Point::new(1, 1), Point::new(2, 1), &"player"));
+    /// assert!(!tree.relocate(Point::new(2, 1), Point::new(8, 1), &"player"));
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![(Point::new(2, 1), &"player")]);
+    /// ```
+    pub fn relocate(&mut self, from: Point, to: Point, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        if !self.bounds.contains(to) {
+            return false;
+        }
+        match self.remove(from, value) {
+            Some(removed) => {
+                self.root.insert(self.bounds, to, removed);
+                self.len += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns an iterator over every stored value and its position, in no
+    /// particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let mut found = Vec::with_capacity(self.len);
+        self.root
+            .visit(self.bounds, &|_| true, &mut |p, v| found.push((p, v)));
+        found.into_iter()
+    }
+
+    /// Returns every value whose position is inside rect, in no particular
+    /// order.
+    pub fn query_rect(&self, rect: &Rect) -> Vec<(Point, &T)> {
+        let mut found = Vec::new();
+        self.root.visit(
+            self.bounds,
+            &|bounds| bounds.intersects(rect),
+            &mut |p, v| {
+                if rect.contains(p) {
+                    found.push((p, v));
+                }
+            },
+        );
+
+        found
+    }
+
+    /// Returns every value whose position is within radius of centre under
+    /// the given metric, in no particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, QuadTree, Rect};
+    /// use point::metric::{Chebyshev, Manhattan};
+    ///
+    /// let mut tree = QuadTree::new(Rect::new(Point::new(-10, -10), Point::new(10, 10)));
+    /// tree.insert(Point::new(2, 2), 'a').unwrap();
+    /// tree.insert(Point::new(3, 0), 'b').unwrap();
+    ///
+    /// assert_eq!(tree.query_radius(Point::ORIGIN, 3, Manhattan), vec![(Point::new(3, 0), &'b')]);
+    /// assert_eq!(tree.query_radius(Point::ORIGIN, 2, Chebyshev), vec![(Point::new(2, 2), &'a')]);
+    /// ```
+    pub fn query_radius<M: Metric>(
+        &self,
+        centre: Point,
+        radius: u32,
+        metric: M,
+    ) -> Vec<(Point, &T)> {
+        let (min, max) = radius_corners(centre, radius);
+        let mut found = Vec::new();
+        self.root.visit(
+            self.bounds,
+            &|bounds| {
+                !bounds.is_empty()
+                    && bounds.min.x <= max.x
+                    && bounds.min.y <= max.y
+                    && bounds.max.x > min.x
+                    && bounds.max.y > min.y
+            },
+            &mut |p, v| {
+                if metric.within(centre, p, radius) {
+                    found.push((p, v));
+                }
+            },
+        );
+
+        found
+    }
+
+    /// Returns the value nearest to pos, or None if the tree is empty. Ties
+    /// are broken in favour of the lowest position in row-major order.
+    pub fn nearest<M: Metric>(&self, pos: Point, metric: M) -> Option<(Point, &T)> {
+        self.k_nearest(pos, 1, metric).pop()
+    }
+
+    /// Returns the k values nearest to pos, nearest first. Returns fewer than
+    /// k values if fewer are stored. Ties are broken in favour of the lowest
+    /// position in row-major order.
+    pub fn k_nearest<M: Metric>(&self, pos: Point, k: usize, metric: M) -> Vec<(Point, &T)> {
+        enum Entry<'a, T> {
+            Node(&'a Node<T>),
+            Value(Point, &'a T),
+        }
+
+        if k == 0 || self.bounds.is_empty() {
+            return Vec::new();
+        }
+        let mut found: Vec<(u64, Point, &T)> = Vec::new();
+
+        // Visit nodes and values in order of their distance from pos. The
+        // distance to a node is the distance to the nearest point in its
+        // bounds, so no value in it can be nearer.
+        let mut entries = vec![Entry::Node(&self.root)];
+        let mut heap = BinaryHeap::from([Reverse((0, 0))]);
+
+        while let Some(Reverse((dist, index))) = heap.pop() {
+            match entries[index] {
+                Entry::Value(p, v) => {
+                    // Keep going until no closer value could be found, so that
+                    // ties are broken consistently.
+                    if found.len() >= k && found[k - 1].0 < dist {
+                        break;
+                    }
+                    found.push((dist, p, v));
+                }
+                Entry::Node(Node::Leaf(items)) => {
+                    for (p, v) in items {
+                        heap.push(Reverse((dist_key(&metric, pos, *p), entries.len())));
+                        entries.push(Entry::Value(*p, v));
+                    }
+                }
+                Entry::Node(Node::Branch(children)) => {
+                    for (rect, child) in children.iter() {
+                        if !rect.is_empty() {
+                            let dist = dist_key(&metric, pos, rect.clamp_point(pos));
+                            heap.push(Reverse((dist, entries.len())));
+                            entries.push(Entry::Node(child));
+                        }
+                    }
+                }
+            }
+        }
+
+        nearest_k(found, k)
+    }
+}
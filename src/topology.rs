@@ -0,0 +1,372 @@
+//! Bounded maps whose edges may wrap around.
+//!
+//! A [Topology] describes a rectangular map anchored at the origin, like the
+//! one checked by [Point::bounds_check], along with which of its edges are
+//! joined to the opposite edge. Neighbours, distances and lines all take the
+//! shortest route, which may cross a seam where the map wraps around.
+use crate::{LineIter, Point, Rect, pathfinding::Neighbourhood};
+
+/// Which edges of a [Topology] wrap around to the opposite edge.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Wrap {
+    /// No edges wrap; points beyond the edges are outside of the map.
+    Clipped,
+    /// The left and right edges are joined, forming a cylinder.
+    WrapX,
+    /// The top and bottom edges are joined, forming a cylinder.
+    WrapY,
+    /// Both pairs of edges are joined, forming a torus.
+    Torus,
+}
+
+/// A map of the given width and height with its minimum corner at the origin,
+/// whose edges may wrap around.
+///
+/// # Examples
+///
+/// ```
+/// use point::Point;
+/// use point::topology::{Topology, Wrap};
+///
+/// let torus = Topology::new(10, 8, Wrap::Torus);
+/// let (a, b) = (Point::new(1, 1), Point::new(9, 7));
+///
+/// assert_eq!(torus.manhattan_dist(a, b), 4);
+/// assert_eq!(torus.normalize(Point::new(-1, 8)), Some(Point::new(9, 0)));
+/// assert_eq!(torus.adjacent(Point::new(0, 0)).count(), 4);
+///
+/// let clipped = Topology::new(10, 8, Wrap::Clipped);
+///
+/// assert_eq!(clipped.manhattan_dist(a, b), 14);
+/// assert_eq!(clipped.normalize(Point::new(-1, 8)), None);
+/// assert_eq!(clipped.adjacent(Point::new(0, 0)).count(), 2);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Topology {
+    width: i32,
+    height: i32,
+    wrap: Wrap,
+}
+
+impl Topology {
+    /// Returns a new topology of the given size, with the given edges
+    /// wrapping around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if width or height is not positive.
+    pub const fn new(width: i32, height: i32, wrap: Wrap) -> Self {
+        assert!(
+            width > 0 && height > 0,
+            "topology dimensions must be positive"
+        );
+        Self {
+            width,
+            height,
+            wrap,
+        }
+    }
+
+    /// Returns the number of columns in the map.
+    #[inline]
+    pub const fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Returns the number of rows in the map.
+    #[inline]
+    pub const fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Returns which edges of the map wrap around.
+    #[inline]
+    pub const fn wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    /// Returns true if the left and right edges are joined.
+    #[inline]
+    pub const fn wraps_x(&self) -> bool {
+        matches!(self.wrap, Wrap::WrapX | Wrap::Torus)
+    }
+
+    /// Returns true if the top and bottom edges are joined.
+    #[inline]
+    pub const fn wraps_y(&self) -> bool {
+        matches!(self.wrap, Wrap::WrapY | Wrap::Torus)
+    }
+
+    /// Returns the rect covering every position in the map.
+    #[inline]
+    pub const fn bounds(&self) -> Rect {
+        Rect::from_size(Point::ORIGIN, self.width, self.height)
+    }
+
+    /// Returns true if the point lies within the map without needing to be
+    /// wrapped.
+    #[inline]
+    pub const fn contains(&self, pos: Point) -> bool {
+        pos.bounds_check(self.width, self.height)
+    }
+
+    /// Returns the point with each co-ordinate along a wrapping axis moved
+    /// into the map. Co-ordinates along clipped axes are left unchanged, so
+    /// the result may still lie outside of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::topology::{Topology, Wrap};
+    ///
+    /// let cylinder = Topology::new(5, 5, Wrap::WrapX);
+    ///
+    /// assert_eq!(cylinder.wrap_point(Point::new(-7, -7)), Point::new(3, -7));
+    /// ```
+    #[inline]
+    pub const fn wrap_point(&self, pos: Point) -> Point {
+        Point::new(
+            if self.wraps_x() {
+                pos.x.rem_euclid(self.width)
+            } else {
+                pos.x
+            },
+            if self.wraps_y() {
+                pos.y.rem_euclid(self.height)
+            } else {
+                pos.y
+            },
+        )
+    }
+
+    /// Returns the position within the map equivalent to pos, or None if pos
+    /// lies beyond a clipped edge.
+    #[inline]
+    pub const fn normalize(&self, pos: Point) -> Option<Point> {
+        let pos = self.wrap_point(pos);
+        if self.contains(pos) { Some(pos) } else { None }
+    }
+
+    /// Returns the shortest displacement from a to b, which may cross a seam.
+    /// Where the two ways around a wrapping axis are equally short, the
+    /// positive one is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::topology::{Topology, Wrap};
+    ///
+    /// let torus = Topology::new(10, 10, Wrap::Torus);
+    ///
+    /// assert_eq!(torus.delta(Point::new(8, 2), Point::new(1, 4)), Point::new(3, 2));
+    /// assert_eq!(torus.delta(Point::new(0, 0), Point::new(5, 6)), Point::new(5, -4));
+    /// ```
+    ///
+    /// Along a clipped axis the displacement may not fit in an i32, in which
+    /// case it saturates:
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::topology::{Topology, Wrap};
+    ///
+    /// let cylinder = Topology::new(10, 10, Wrap::WrapX);
+    /// let (a, b) = (Point::new(1, i32::MIN), Point::new(8, i32::MAX));
+    ///
+    /// assert_eq!(cylinder.delta(a, b), Point::new(-3, i32::MAX));
+    /// assert_eq!(cylinder.delta(b, a), Point::new(3, i32::MIN));
+    /// ```
+    pub const fn delta(&self, a: Point, b: Point) -> Point {
+        let (dx, dy) = self.wide_delta(a, b);
+        Point::new(saturate(dx), saturate(dy))
+    }
+
+    /// The same as delta, but the displacement is returned as a pair of i64s,
+    /// so that it does not overflow along clipped axes.
+    const fn wide_delta(&self, a: Point, b: Point) -> (i64, i64) {
+        (
+            shortest(a.x, b.x, self.width, self.wraps_x()),
+            shortest(a.y, b.y, self.height, self.wraps_y()),
+        )
+    }
+
+    /// Returns the manhattan distance between a and b, taking the shortest
+    /// route across any seams. As with [Point::manhattan_dist], the result
+    /// saturates at i32::MAX.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::topology::{Topology, Wrap};
+    ///
+    /// let cylinder = Topology::new(10, 10, Wrap::WrapX);
+    ///
+    /// assert_eq!(cylinder.manhattan_dist(Point::new(1, 0), Point::new(8, 2)), 5);
+    /// assert_eq!(cylinder.manhattan_dist(Point::new(1, i32::MIN), Point::new(8, i32::MAX)), i32::MAX);
+    /// ```
+    #[inline]
+    pub const fn manhattan_dist(&self, a: Point, b: Point) -> i32 {
+        let (dx, dy) = self.wide_delta(a, b);
+        saturate_unsigned(dx.unsigned_abs() + dy.unsigned_abs())
+    }
+
+    /// Returns the Chebyshev distance between a and b, taking the shortest
+    /// route across any seams. As with manhattan_dist, the result saturates
+    /// at i32::MAX.
+    #[inline]
+    pub const fn chebyshev_dist(&self, a: Point, b: Point) -> i32 {
+        let (dx, dy) = self.wide_delta(a, b);
+        let (dx, dy) = (dx.unsigned_abs(), dy.unsigned_abs());
+        saturate_unsigned(if dx > dy { dx } else { dy })
+    }
+
+    /// Returns the squared Euclidean distance between a and b, taking the
+    /// shortest route across any seams. As with [Point::dist_squared_wide],
+    /// the result is a u128, which cannot overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::topology::{Topology, Wrap};
+    ///
+    /// let cylinder = Topology::new(10, 10, Wrap::WrapX);
+    /// let (a, b) = (Point::new(1, i32::MIN), Point::new(8, i32::MAX));
+    ///
+    /// assert_eq!(cylinder.dist_squared(a, b), 9 + (u32::MAX as u128).pow(2));
+    /// ```
+    #[inline]
+    pub const fn dist_squared(&self, a: Point, b: Point) -> u128 {
+        let (dx, dy) = self.wide_delta(a, b);
+        let (dx, dy) = (dx.unsigned_abs() as u128, dy.unsigned_abs() as u128);
+        dx * dx + dy * dy
+    }
+
+    /// Returns the Euclidean distance between a and b, taking the shortest
+    /// route across any seams.
+    #[inline]
+    pub fn dist(&self, a: Point, b: Point) -> f64 {
+        (self.dist_squared(a, b) as f64).sqrt()
+    }
+
+    /// Returns an iterator over the positions within the map orthogonally
+    /// adjacent to pos. On a wrapping axis of length 1 or 2, the same
+    /// position may be returned more than once, or pos itself may be returned.
+    pub fn adjacent(&self, pos: Point) -> impl Iterator<Item = Point> + use<> {
+        let topology = *self;
+        pos.adjacent()
+            .into_iter()
+            .filter_map(move |p| topology.normalize(p))
+    }
+
+    /// The same as adjacent, but also includes diagonally adjacent positions.
+    pub fn adjacent_diagonal(&self, pos: Point) -> impl Iterator<Item = Point> + use<> {
+        let topology = *self;
+        pos.adjacent_diagonal()
+            .into_iter()
+            .filter_map(move |p| topology.normalize(p))
+    }
+
+    /// Returns an iterator over the positions within the map adjacent to pos
+    /// in the given neighbourhood.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::pathfinding::Neighbourhood;
+    /// use point::topology::{Topology, Wrap};
+    ///
+    /// let cylinder = Topology::new(4, 4, Wrap::WrapY);
+    /// let neighbours: Vec<Point> = cylinder.neighbours(Point::new(0, 3), Neighbourhood::Four).collect();
+    ///
+    /// assert_eq!(neighbours, vec![Point::new(1, 3), Point::new(0, 2), Point::new(0, 0)]);
+    /// ```
+    pub fn neighbours(
+        &self,
+        pos: Point,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = Point> + use<> {
+        let topology = *self;
+        neighbourhood
+            .neighbours(pos)
+            .filter_map(move |p| topology.normalize(p))
+    }
+
+    /// Returns an iterator over the points on the shortest line from src to
+    /// dest, wrapped into the map, using the same points as
+    /// [Point::plot_line]. Note that src is included in the line, but dest is
+    /// not.
+    pub fn plot_line(&self, src: Point, dest: Point) -> impl Iterator<Item = Point> + use<> {
+        self.wrap_line(Point::plot_line(src, self.line_end(src, dest)))
+    }
+
+    /// The same as plot_line, but dest is also included in the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::Point;
+    /// use point::topology::{Topology, Wrap};
+    ///
+    /// let torus = Topology::new(10, 10, Wrap::Torus);
+    /// let line: Vec<Point> = torus.plot_line_inclusive(Point::new(8, 0), Point::new(1, 1)).collect();
+    ///
+    /// let expected: Vec<Point> = vec![(8, 0), (9, 0), (0, 1), (1, 1)].into_iter().map(Point::from).collect();
+    /// assert_eq!(line, expected);
+    /// ```
+    pub fn plot_line_inclusive(
+        &self,
+        src: Point,
+        dest: Point,
+    ) -> impl Iterator<Item = Point> + use<> {
+        self.wrap_line(Point::plot_line_inclusive(src, self.line_end(src, dest)))
+    }
+
+    /// Returns the end of the shortest line from src to dest, before it is
+    /// wrapped into the map. Along clipped axes, this is just dest.
+    const fn line_end(&self, src: Point, dest: Point) -> Point {
+        let (dx, dy) = self.wide_delta(src, dest);
+        Point::new(saturate(src.x as i64 + dx), saturate(src.y as i64 + dy))
+    }
+
+    fn wrap_line(&self, line: LineIter) -> impl Iterator<Item = Point> + use<> {
+        let topology = *self;
+        line.map(move |p| topology.wrap_point(p))
+    }
+}
+
+/// Converts an i64 to an i32, saturating at the bounds of i32.
+const fn saturate(val: i64) -> i32 {
+    if val > i32::MAX as i64 {
+        i32::MAX
+    } else if val < i32::MIN as i64 {
+        i32::MIN
+    } else {
+        val as i32
+    }
+}
+
+/// Converts a u64 to an i32, saturating at i32::MAX.
+const fn saturate_unsigned(val: u64) -> i32 {
+    if val > i32::MAX as u64 {
+        i32::MAX
+    } else {
+        val as i32
+    }
+}
+
+/// Returns the shortest displacement from a to b along an axis of the given
+/// length.
+const fn shortest(a: i32, b: i32, len: i32, wraps: bool) -> i64 {
+    let diff = b as i64 - a as i64;
+    if !wraps {
+        return diff;
+    }
+    let len = len as i64;
+    let diff = diff.rem_euclid(len);
+    if 2 * diff > len { diff - len } else { diff }
+}
@@ -0,0 +1,239 @@
+//! Integer affine transformations of [Point]s.
+use crate::{Point, Rect};
+
+/// An affine transformation of integer co-ordinates; a 2x2 integer matrix
+/// followed by a translation.
+///
+/// The eight symmetries of a square, which map the grid onto itself, are
+/// provided as constants, and can be applied about any pivot point with
+/// [Transform::about]. As with [Point::rotate_90_cw], rotations assume that
+/// the y axis points upwards.
+///
+/// # Examples
+///
+/// ```
+/// use point::{Point, Transform};
+///
+/// let pivot = Point::new(2, 2);
+/// let turn = Transform::ROTATE_90_CW.about(pivot);
+///
+/// assert_eq!(turn.apply(Point::new(2, 3)), Point::new(3, 2));
+/// assert_eq!(turn.apply(pivot), pivot);
+///
+/// // Four quarter turns make a whole turn.
+/// let whole = turn.then(turn).then(turn).then(turn);
+/// assert_eq!(whole, Transform::IDENTITY);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform {
+    /// The rows of the matrix, so that a point p is mapped to
+    /// `(matrix[0][0] * p.x + matrix[0][1] * p.y, matrix[1][0] * p.x + matrix[1][1] * p.y)`
+    /// before being translated.
+    pub matrix: [[i32; 2]; 2],
+    /// The offset added to every point after multiplying by the matrix.
+    pub translation: Point,
+}
+
+impl Transform {
+    /// The transform that leaves every point where it is.
+    pub const IDENTITY: Self = Self::linear([[1, 0], [0, 1]]);
+    /// Rotation about the origin by 90 degrees clockwise.
+    pub const ROTATE_90_CW: Self = Self::linear([[0, 1], [-1, 0]]);
+    /// Rotation about the origin by 180 degrees.
+    pub const ROTATE_180: Self = Self::linear([[-1, 0], [0, -1]]);
+    /// Rotation about the origin by 90 degrees anti-clockwise.
+    pub const ROTATE_90_ACW: Self = Self::linear([[0, -1], [1, 0]]);
+    /// Reflection in the x axis, negating y co-ordinates.
+    pub const REFLECT_X: Self = Self::linear([[1, 0], [0, -1]]);
+    /// Reflection in the y axis, negating x co-ordinates.
+    pub const REFLECT_Y: Self = Self::linear([[-1, 0], [0, 1]]);
+    /// Reflection in the line y = x, swapping the co-ordinates.
+    pub const REFLECT_DIAGONAL: Self = Self::linear([[0, 1], [1, 0]]);
+    /// Reflection in the line y = -x, swapping and negating the co-ordinates.
+    pub const REFLECT_ANTI_DIAGONAL: Self = Self::linear([[0, -1], [-1, 0]]);
+
+    /// The eight symmetries of a square about the origin; the four rotations
+    /// followed by the four reflections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Transform};
+    ///
+    /// // Every orientation of an L shaped prefab.
+    /// let prefab = [Point::new(0, 0), Point::new(0, 1), Point::new(1, 0), Point::new(2, 0)];
+    /// let variants: Vec<Vec<Point>> = Transform::SYMMETRIES
+    ///     .iter()
+    ///     .map(|t| prefab.iter().map(|&p| t.apply(p)).collect())
+    ///     .collect();
+    ///
+    /// assert!(variants.contains(&vec![Point::new(0, 0), Point::new(1, 0), Point::new(0, 1), Point::new(0, 2)]));
+    /// ```
+    pub const SYMMETRIES: [Self; 8] = [
+        Self::IDENTITY,
+        Self::ROTATE_90_CW,
+        Self::ROTATE_180,
+        Self::ROTATE_90_ACW,
+        Self::REFLECT_X,
+        Self::REFLECT_Y,
+        Self::REFLECT_DIAGONAL,
+        Self::REFLECT_ANTI_DIAGONAL,
+    ];
+
+    /// Returns a new transform with the given matrix and translation.
+    #[inline]
+    pub const fn new(matrix: [[i32; 2]; 2], translation: Point) -> Self {
+        Self {
+            matrix,
+            translation,
+        }
+    }
+
+    /// Returns the transform that multiplies by the given matrix without
+    /// translating.
+    #[inline]
+    pub const fn linear(matrix: [[i32; 2]; 2]) -> Self {
+        Self::new(matrix, Point::ORIGIN)
+    }
+
+    /// Returns the transform that adds offset to every point.
+    #[inline]
+    pub const fn translate(offset: Point) -> Self {
+        Self::new([[1, 0], [0, 1]], offset)
+    }
+
+    /// Returns the transform that multiplies the x and y co-ordinates by the
+    /// given factors.
+    #[inline]
+    pub const fn scale(x: i32, y: i32) -> Self {
+        Self::linear([[x, 0], [0, y]])
+    }
+
+    /// Returns the transformed point.
+    #[inline]
+    pub const fn apply(self, p: Point) -> Point {
+        let [[a, b], [c, d]] = self.matrix;
+        Point::new(
+            a * p.x + b * p.y + self.translation.x,
+            c * p.x + d * p.y + self.translation.y,
+        )
+    }
+
+    /// Returns the transform that applies self, then next.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Transform};
+    ///
+    /// let t = Transform::REFLECT_Y.then(Transform::translate(Point::new(5, 0)));
+    ///
+    /// assert_eq!(t.apply(Point::new(1, 2)), Point::new(4, 2));
+    /// assert_eq!(Transform::REFLECT_X.then(Transform::REFLECT_Y), Transform::ROTATE_180);
+    /// ```
+    pub const fn then(self, next: Self) -> Self {
+        let [[a, b], [c, d]] = next.matrix;
+        let [[e, f], [g, h]] = self.matrix;
+
+        Self::new(
+            [
+                [a * e + b * g, a * f + b * h],
+                [c * e + d * g, c * f + d * h],
+            ],
+            next.apply(self.translation),
+        )
+    }
+
+    /// Returns the same transform performed about pivot rather than the
+    /// origin; for a rotation, pivot is the point rotated about, and for a
+    /// reflection, the line reflected in passes through pivot.
+    pub const fn about(self, pivot: Point) -> Self {
+        Self::translate(Point::new(-pivot.x, -pivot.y))
+            .then(self)
+            .then(Self::translate(pivot))
+    }
+
+    /// Returns the determinant of the matrix; the factor by which the
+    /// transform scales areas, which is negative if it reflects them.
+    #[inline]
+    pub const fn determinant(self) -> i32 {
+        let [[a, b], [c, d]] = self.matrix;
+        a * d - b * c
+    }
+
+    /// Returns the transform that undoes self, or None if there is no such
+    /// transform with integer co-ordinates. This is the case unless the
+    /// determinant is 1 or -1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Transform};
+    ///
+    /// let t = Transform::ROTATE_90_CW.about(Point::new(3, 1));
+    /// let p = Point::new(-2, 7);
+    ///
+    /// assert_eq!(t.inverse().unwrap().apply(t.apply(p)), p);
+    /// assert_eq!(Transform::scale(2, 1).inverse(), None);
+    /// ```
+    pub const fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det != 1 && det != -1 {
+            return None;
+        }
+        let [[a, b], [c, d]] = self.matrix;
+        // With a determinant of ±1, dividing by it is the same as multiplying.
+        let linear = Self::linear([[d * det, -b * det], [-c * det, a * det]]);
+        let translation = linear.apply(self.translation);
+
+        Some(Self::new(
+            linear.matrix,
+            Point::new(-translation.x, -translation.y),
+        ))
+    }
+
+    /// Returns true if the matrix is one of the eight symmetries of a square,
+    /// so that the transform maps the grid onto itself without stretching it.
+    #[inline]
+    pub fn is_symmetry(self) -> bool {
+        Self::SYMMETRIES.iter().any(|sym| sym.matrix == self.matrix)
+    }
+
+    /// Returns the smallest rect containing every transformed point of rect.
+    /// For symmetries, this is exactly the transformed points. An empty rect
+    /// is transformed to an empty rect at the transformed minimum corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use point::{Point, Rect, Transform};
+    ///
+    /// let room = Rect::new(Point::new(1, 1), Point::new(4, 3));
+    ///
+    /// assert_eq!(Transform::ROTATE_90_CW.apply_rect(&room), Rect::new(Point::new(1, -3), Point::new(3, 0)));
+    /// ```
+    pub fn apply_rect(self, rect: &Rect) -> Rect {
+        if rect.is_empty() {
+            let min = self.apply(rect.min);
+            return Rect::new(min, min);
+        }
+        let (lo, hi) = (rect.min, rect.max - Point::new(1, 1));
+        let corners =
+            [lo, Point::new(hi.x, lo.y), Point::new(lo.x, hi.y), hi].map(|p| self.apply(p));
+
+        let min = corners.iter().fold(corners[0], |min, p| {
+            Point::new(min.x.min(p.x), min.y.min(p.y))
+        });
+        let max = corners.iter().fold(corners[0], |max, p| {
+            Point::new(max.x.max(p.x), max.y.max(p.y))
+        });
+        Rect::from_corners(min, max)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}